@@ -2,13 +2,21 @@
 
 use benchmarks::{
     // Neo4j benchmarks
-    neo4j_create_links, neo4j_delete_links, neo4j_each_all, neo4j_each_concrete,
-    neo4j_each_identity, neo4j_each_incoming, neo4j_each_outgoing, neo4j_update_links,
+    neo4j_bulk_create, neo4j_bulk_delete, neo4j_concurrent_throughput, neo4j_create_links,
+    neo4j_delete_links, neo4j_each_all, neo4j_each_concrete, neo4j_each_identity,
+    neo4j_each_incoming, neo4j_each_outgoing, neo4j_graph_traversal, neo4j_scaling_curve,
+    neo4j_snapshot_export, neo4j_snapshot_import, neo4j_update_links,
     // Doublets benchmarks
+    doublets_bulk_create, doublets_bulk_delete, doublets_concurrent_throughput,
     doublets_create_links, doublets_delete_links, doublets_each_all, doublets_each_concrete,
-    doublets_each_identity, doublets_each_incoming, doublets_each_outgoing, doublets_update_links,
+    doublets_each_identity, doublets_each_incoming, doublets_each_outgoing,
+    doublets_graph_traversal, doublets_scaling_curve, doublets_snapshot_export,
+    doublets_snapshot_import, doublets_update_links,
+    // Closed-loop load test and its worker-pool sibling -- not
+    // criterion_group! members, see benchmarks::load's module doc.
+    run_concurrent_load_test, run_load_test,
 };
-use criterion::{criterion_group, criterion_main};
+use criterion::{criterion_group, Criterion};
 
 mod benchmarks;
 
@@ -32,7 +40,14 @@ criterion_group!(
     neo4j_each_outgoing,
     neo4j_each_incoming,
     neo4j_each_all,
-    neo4j_update_links
+    neo4j_update_links,
+    neo4j_bulk_create,
+    neo4j_bulk_delete,
+    neo4j_concurrent_throughput,
+    neo4j_scaling_curve,
+    neo4j_graph_traversal,
+    neo4j_snapshot_export,
+    neo4j_snapshot_import
 );
 
 // Doublets benchmarks group
@@ -45,7 +60,35 @@ criterion_group!(
     doublets_each_outgoing,
     doublets_each_incoming,
     doublets_each_all,
-    doublets_update_links
+    doublets_update_links,
+    doublets_bulk_create,
+    doublets_bulk_delete,
+    doublets_concurrent_throughput,
+    doublets_scaling_curve,
+    doublets_graph_traversal,
+    doublets_snapshot_export,
+    doublets_snapshot_import
 );
 
-criterion_main!(neo4j_benches, doublets_benches);
+// Criterion's own `criterion_main!` doesn't give us a hook to run after all
+// groups finish, and `MetricsSink::export` needs exactly that: it must see
+// every `elapsed!` sample from both groups before writing the summary file.
+// `run_load_test` and `run_concurrent_load_test` are their own thing
+// entirely -- each a no-op unless its own env vars are set, since a
+// fixed-duration sustained-rate run doesn't fit `criterion_group!`'s
+// iteration-count-driven shape.
+fn main() {
+    neo4j_benches();
+    doublets_benches();
+    run_load_test();
+    run_concurrent_load_test();
+
+    if let Err(err) = linksneo4j::metrics::METRICS.export() {
+        eprintln!("failed to export benchmark metrics: {err}");
+    }
+    if let Err(err) = linksneo4j::counters::COUNTERS.export() {
+        eprintln!("failed to export benchmark counters: {err}");
+    }
+
+    Criterion::default().configure_from_args().final_summary();
+}
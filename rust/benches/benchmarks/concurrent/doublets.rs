@@ -0,0 +1,138 @@
+//! Doublets side of the concurrent-throughput benchmark. Every benchmark
+//! iteration forks the shared backend instance into `workers` concurrent
+//! forks via [`linksneo4j::Benched::fork_concurrent`] (see [`run_locked_pool`]),
+//! which for these backends means every worker forks the same
+//! `Mutex`-guarded store -- so this specifically measures lock
+//! contention on `Doublets_United_*`/`Doublets_Split_*` instead of giving
+//! each worker its own store, while still tearing every fork down between
+//! iterations instead of letting data accumulate across them.
+//!
+//! Besides the aggregate ops/sec Criterion reports for the group, each
+//! individual op's latency is also fed into [`linksneo4j::metrics::METRICS`]
+//! under `(id, "Concurrent_Create"/"Concurrent_Each"/"Concurrent_Update")`,
+//! so `MetricsSink::export` surfaces p50/p90/p99 tail latency under
+//! contention, not just the mean implied by the aggregate throughput.
+
+use std::{
+    alloc::Global,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
+use doublets::{
+    data::{Flow, LinksConstants},
+    mem::{Alloc, FileMapped},
+    parts::LinkPart,
+    split::{self, DataPart, IndexPart},
+    unit, Doublets,
+};
+use linksneo4j::{metrics::METRICS, selector::SELECTOR, worker_count, Benched, Exclusive, Sqlite};
+
+use super::pool::run_locked_pool;
+use crate::tri;
+
+/// Number of mixed operations each worker performs per benchmark iteration.
+const OPS_PER_WORKER: usize = 1_000;
+
+/// Runs one mixed create/read/update operation for the given disjoint id,
+/// returning the operation label it was recorded under.
+fn run_op<B: Doublets<usize>>(store: &mut B, any: usize, op: usize, id: usize) -> &'static str {
+    let handler = |_| Flow::Continue;
+    match op % 3 {
+        0 => {
+            let _ = store.create_point();
+            "Concurrent_Create"
+        }
+        1 => {
+            store.each_by([any, id, any], handler);
+            "Concurrent_Each"
+        }
+        _ => {
+            let _ = store.update(id, id, id);
+            "Concurrent_Update"
+        }
+    }
+}
+
+fn bench<B>(group: &mut BenchmarkGroup<WallTime>, id: &str, benched: B)
+where
+    B: Benched + Doublets<usize> + Send,
+{
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let workers = worker_count();
+    let any = LinksConstants::new().any;
+    let mut benched = benched;
+
+    group.throughput(Throughput::Elements((workers * OPS_PER_WORKER) as u64));
+    group.bench_function(format!("{id}/{workers}"), move |bencher| {
+        bencher.iter_custom(|iters| {
+            let mut duration = Duration::ZERO;
+            for _ in 0..iters {
+                let completed = AtomicUsize::new(0);
+                duration += run_locked_pool(&mut benched, workers, OPS_PER_WORKER, |store, worker, offset| {
+                    let op_start = std::time::Instant::now();
+                    let label = run_op(store, any, offset, worker * OPS_PER_WORKER + offset + 1);
+                    METRICS.record(id, label, op_start.elapsed());
+                    completed.fetch_add(1, Ordering::Relaxed);
+                });
+            }
+            duration
+        });
+    });
+}
+
+/// Creates the concurrent-throughput benchmark for the Doublets backends.
+pub fn concurrent_throughput(c: &mut Criterion) {
+    if !SELECTOR.operation("concurrent_throughput") {
+        return;
+    }
+    let mut group = c.benchmark_group("Concurrent_Throughput");
+
+    tri! {
+        bench(
+            &mut group,
+            "Doublets_United_Volatile",
+            unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(()).unwrap()
+        )
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Doublets_United_NonVolatile",
+            unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links").unwrap()
+        )
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Doublets_Split_Volatile",
+            split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(()).unwrap()
+        )
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Doublets_Split_NonVolatile",
+            split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap()
+        )
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        )
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        )
+    }
+
+    group.finish();
+}
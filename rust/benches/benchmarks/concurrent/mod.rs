@@ -0,0 +1,31 @@
+//! # Concurrent Throughput Benchmark
+//!
+//! Every other benchmark in this crate drives its backend from a single
+//! thread in a tight loop, which hides contention behavior entirely --
+//! and a links store's real value is how it behaves under concurrent
+//! access. This module spawns a configurable number of worker threads
+//! (see [`linksneo4j::worker_count`], env var `WORKERS`) that each hammer
+//! a backend with a mix of `create_point`, `each_by`, and `update`
+//! calls over disjoint id ranges, reporting both aggregate ops/sec (via
+//! Criterion's `Throughput`) and, through
+//! [`linksneo4j::metrics::METRICS`], per-operation p50/p90/p99 tail latency
+//! under contention.
+//!
+//! ## Module Structure
+//!
+//! - **[`neo4j`]** - Each worker opens its own `Client` via `connect()`, so
+//!   the benchmark measures how the HTTP server handles concurrent
+//!   transactional Cypher traffic.
+//! - **[`doublets`]** - All workers share one backend instance behind a
+//!   `Mutex`, so the benchmark exposes lock
+//!   contention across `Doublets_United_*` and `Doublets_Split_*`.
+//! - **[`pool`]** - The `Benched::fork_concurrent`-backed worker pool
+//!   [`doublets`] spreads its workload over, factored out for reuse by
+//!   future pool-backed benchmarks.
+
+mod doublets;
+mod neo4j;
+mod pool;
+
+pub use self::doublets::concurrent_throughput as doublets_concurrent_throughput;
+pub use neo4j::concurrent_throughput as neo4j_concurrent_throughput;
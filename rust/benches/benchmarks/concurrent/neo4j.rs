@@ -0,0 +1,111 @@
+//! Neo4j side of the concurrent-throughput benchmark. Each worker opens its
+//! own `Client` via `connect()`, so this measures how the HTTP server
+//! itself handles concurrent transactional Cypher traffic, rather than
+//! contention on a client-side lock.
+//!
+//! Besides the aggregate ops/sec Criterion reports for the group, each
+//! individual request's latency is also fed into
+//! [`linksneo4j::metrics::METRICS`] under `(id, "Concurrent_Create"/
+//! "Concurrent_Each"/"Concurrent_Update")`, so `MetricsSink::export`
+//! surfaces p50/p90/p99 tail latency under concurrent load, not just the
+//! mean implied by the aggregate throughput.
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Barrier,
+    },
+    time::{Duration, Instant},
+};
+
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
+use crossbeam::thread;
+use linksneo4j::{connect, metrics::METRICS, selector::SELECTOR, worker_count};
+use serde_json::json;
+
+use crate::tri;
+
+/// Number of mixed operations each worker performs per benchmark iteration.
+const OPS_PER_WORKER: usize = 1_000;
+
+/// Runs one mixed create/read/update operation for the given disjoint id,
+/// returning the operation label it was recorded under.
+fn run_op(client: &linksneo4j::Client<usize>, op: usize, id: usize) -> &'static str {
+    match op % 3 {
+        0 => {
+            let _ = client.execute_cypher(
+                "CREATE (l:Link {id: $id, source: 0, target: 0})",
+                Some(json!({ "id": id })),
+            );
+            "Concurrent_Create"
+        }
+        1 => {
+            let _ = client.execute_cypher(
+                "MATCH (l:Link) WHERE l.source = $source RETURN l.id",
+                Some(json!({ "source": id })),
+            );
+            "Concurrent_Each"
+        }
+        _ => {
+            let _ = client.execute_cypher(
+                "MATCH (l:Link {id: $id}) SET l.source = $id, l.target = $id",
+                Some(json!({ "id": id })),
+            );
+            "Concurrent_Update"
+        }
+    }
+}
+
+fn bench(group: &mut BenchmarkGroup<WallTime>, id: &str) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let workers = worker_count();
+    group.throughput(Throughput::Elements((workers * OPS_PER_WORKER) as u64));
+    group.bench_function(format!("{id}/{workers}"), |bencher| {
+        bencher.iter_custom(|iters| {
+            let mut duration = Duration::ZERO;
+            for _ in 0..iters {
+                let barrier = Barrier::new(workers);
+                let completed = AtomicUsize::new(0);
+
+                let start = Instant::now();
+                thread::scope(|scope| {
+                    for worker in 0..workers {
+                        let barrier = &barrier;
+                        let completed = &completed;
+                        scope.spawn(move |_| {
+                            let client = connect().expect("connect to Neo4j");
+                            barrier.wait();
+
+                            let base = worker * OPS_PER_WORKER;
+                            for offset in 0..OPS_PER_WORKER {
+                                let op_start = Instant::now();
+                                let label = run_op(&client, offset, base + offset + 1);
+                                METRICS.record(id, label, op_start.elapsed());
+                                completed.fetch_add(1, Ordering::Relaxed);
+                            }
+                        });
+                    }
+                })
+                .expect("worker threads panicked");
+                duration += start.elapsed();
+            }
+            duration
+        });
+    });
+}
+
+/// Creates the concurrent-throughput benchmark for the Neo4j backends.
+pub fn concurrent_throughput(c: &mut Criterion) {
+    if !SELECTOR.operation("concurrent_throughput") {
+        return;
+    }
+    let mut group = c.benchmark_group("Concurrent_Throughput");
+
+    tri! {
+        bench(&mut group, "Neo4j_NonTransaction");
+    }
+
+    group.finish();
+}
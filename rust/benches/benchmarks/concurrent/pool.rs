@@ -0,0 +1,63 @@
+//! # Concurrent Fork Pool
+//!
+//! [`run_locked_pool`] spreads a mixed workload across `workers` threads by
+//! driving each one from its own [`ConcurrentFork`], via
+//! [`Benched::fork_concurrent`]. For `Doublets_United_*`/`Doublets_Split_*`/
+//! `Sqlite_*` -- which have no notion of an independent per-client session
+//! the way a Neo4j connection does -- that default implementation hands
+//! every worker a fork of the *same* `Mutex`-guarded store, so two workers
+//! are never inside a [`doublets::Doublets`] call at the same instant and a
+//! volatile/file-mapped store can't observe a torn write. `Client`-backed
+//! backends don't need that: see [`super::neo4j`], where each worker opens
+//! its own connection entirely outside the `Benched` lifecycle.
+//!
+//! Every worker starts from a [`Barrier`] so the timed region begins only
+//! once all of them are ready, the same synchronization
+//! [`super::doublets::bench`] already used inline before this was factored
+//! out into its own helper; and every fork is torn down via
+//! [`Benched::unfork`] when the pool finishes, rather than accumulating
+//! data across benchmark iterations the way the original inline version did.
+
+use std::{
+    sync::Barrier,
+    time::{Duration, Instant},
+};
+
+use crossbeam::thread;
+use linksneo4j::Benched;
+
+/// Forks `benched` into `workers` concurrent forks (see
+/// [`Benched::fork_concurrent`]), spreads `ops_per_worker` calls to `op`
+/// across them, and returns the wall-clock time the whole pool took. `op`
+/// receives this call's fork, this worker's index (`0..workers`) and this
+/// call's index within the worker (`0..ops_per_worker`), enough for callers
+/// to derive disjoint ids the way [`super::doublets::run_op`] already does.
+/// Every fork is torn down, via `Drop`, before this function returns.
+pub fn run_locked_pool<B: Benched + Send>(
+    benched: &mut B,
+    workers: usize,
+    ops_per_worker: usize,
+    op: impl Fn(&mut B, usize, usize) + Sync,
+) -> Duration {
+    let forks = benched.fork_concurrent(workers);
+    let barrier = Barrier::new(forks.len() + 1);
+    let mut start = Instant::now();
+
+    thread::scope(|scope| {
+        for (worker, mut fork) in forks.into_iter().enumerate() {
+            let barrier = &barrier;
+            let op = &op;
+            scope.spawn(move |_| {
+                barrier.wait();
+                for index in 0..ops_per_worker {
+                    fork.with(|store: &mut B| op(store, worker, index));
+                }
+            });
+        }
+        barrier.wait();
+        start = Instant::now();
+    })
+    .expect("pool worker threads panicked");
+
+    start.elapsed()
+}
@@ -0,0 +1,202 @@
+//! # Doublets Bulk Write Benchmark
+//!
+//! Mirrors the Neo4j `UNWIND`-batched bulk write benchmark so both backends
+//! are compared at identical write granularity: creates (or deletes)
+//! `batch` links inside a single timed region per iteration instead of
+//! timing each `create_point`/`delete` call separately.
+
+use std::{
+    alloc::Global,
+    time::{Duration, Instant},
+};
+
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
+use doublets::{
+    mem::{Alloc, FileMapped},
+    parts::LinkPart,
+    split::{self, DataPart, IndexPart},
+    unit, Doublets,
+};
+use linksneo4j::{
+    batch_sizes, bench, selector::SELECTOR, Benched, Exclusive, Fork, RocksDb, Sqlite,
+};
+
+use crate::tri;
+
+/// Runs the bulk create benchmark on a Doublets backend, once per batch size.
+fn bench_create<B: Benched + Doublets<usize>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: &str,
+    mut benched: B,
+) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    for batch in batch_sizes() {
+        if !SELECTOR.size(batch) {
+            continue;
+        }
+        group.throughput(Throughput::Elements(batch as u64));
+        group.bench_function(format!("{id}/{batch}"), |bencher| {
+            bench!(|fork| as B, "Bulk_Create" {
+                let _ = elapsed! {
+                    for _ in 0..batch {
+                        fork.create_point()?;
+                    }
+                };
+            })(bencher, &mut benched, id);
+        });
+    }
+}
+
+/// Runs the bulk delete benchmark on a Doublets backend, once per batch size.
+fn bench_delete<B: Benched + Doublets<usize>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: &str,
+    mut benched: B,
+) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    for batch in batch_sizes() {
+        if !SELECTOR.size(batch) {
+            continue;
+        }
+        group.throughput(Throughput::Elements(batch as u64));
+        group.bench_function(format!("{id}/{batch}"), |bencher| {
+            bench!(|fork| as B, "Bulk_Delete" {
+                use linksneo4j::BACKGROUND_LINKS;
+                // Prepare: create the links this iteration will delete.
+                for _prepare in *BACKGROUND_LINKS..*BACKGROUND_LINKS + batch {
+                    let _ = fork.create_point();
+                }
+
+                let _ = elapsed! {
+                    for id in (*BACKGROUND_LINKS + 1..=*BACKGROUND_LINKS + batch).rev() {
+                        fork.delete(id)?;
+                    }
+                };
+            })(bencher, &mut benched, id);
+        });
+    }
+}
+
+/// Creates benchmark for Doublets backends on batched link creation.
+pub fn bulk_create(c: &mut Criterion) {
+    if !SELECTOR.operation("bulk_create") {
+        return;
+    }
+    let mut group = c.benchmark_group("Bulk_Create");
+
+    tri! {
+        bench_create(
+            &mut group,
+            "Doublets_United_Volatile",
+            unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(()).unwrap()
+        )
+    }
+    tri! {
+        bench_create(
+            &mut group,
+            "Doublets_United_NonVolatile",
+            unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links").unwrap()
+        )
+    }
+    tri! {
+        bench_create(
+            &mut group,
+            "Doublets_Split_Volatile",
+            split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(()).unwrap()
+        )
+    }
+    tri! {
+        bench_create(
+            &mut group,
+            "Doublets_Split_NonVolatile",
+            split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap()
+        )
+    }
+    tri! {
+        bench_create(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench_create(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
+    tri! {
+        bench_create(
+            &mut group,
+            "Doublets_RocksDB",
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench").unwrap(),
+        );
+    }
+
+    group.finish();
+}
+
+/// Creates benchmark for Doublets backends on batched link deletion.
+pub fn bulk_delete(c: &mut Criterion) {
+    if !SELECTOR.operation("bulk_delete") {
+        return;
+    }
+    let mut group = c.benchmark_group("Bulk_Delete");
+
+    tri! {
+        bench_delete(
+            &mut group,
+            "Doublets_United_Volatile",
+            unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(()).unwrap()
+        )
+    }
+    tri! {
+        bench_delete(
+            &mut group,
+            "Doublets_United_NonVolatile",
+            unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links").unwrap()
+        )
+    }
+    tri! {
+        bench_delete(
+            &mut group,
+            "Doublets_Split_Volatile",
+            split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(()).unwrap()
+        )
+    }
+    tri! {
+        bench_delete(
+            &mut group,
+            "Doublets_Split_NonVolatile",
+            split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap()
+        )
+    }
+    tri! {
+        bench_delete(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench_delete(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
+    tri! {
+        bench_delete(
+            &mut group,
+            "Doublets_RocksDB",
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench").unwrap(),
+        );
+    }
+
+    group.finish();
+}
@@ -6,7 +6,10 @@
 //!
 //! Doublets creates links by:
 //! - Allocating next available ID from internal counter
-//! - Writing (id, id, id) tuple directly to memory/file
+//! - Writing (id, source, target) directly to memory/file -- `source`/
+//!   `target` come from [`linksneo4j::workload::generate`] rather than
+//!   the degenerate `(id, id, id)` self-loop, so the source/target index
+//!   updates below are exercised against realistic, non-uniform degree
 //! - Updating source and target indexes
 //! - Time complexity: O(log n) for index updates
 
@@ -15,35 +18,56 @@ use std::{
     time::{Duration, Instant},
 };
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
 use doublets::{
     mem::{Alloc, FileMapped},
     parts::LinkPart,
     split::{self, DataPart, IndexPart},
     unit, Doublets,
 };
-use linksneo4j::{bench, Benched, Fork, LINK_COUNT};
+use linksneo4j::{
+    bench,
+    counters::{WorkCounted, COUNTERS},
+    selector::SELECTOR,
+    workload, Benched, Exclusive, Fork, RocksDb, Sqlite, LINK_COUNT,
+};
 
 use crate::tri;
 
 /// Runs the create benchmark on a Doublets backend.
-fn bench<B: Benched + Doublets<usize>>(
+fn bench<B: Benched + Doublets<usize> + WorkCounted>(
     group: &mut BenchmarkGroup<WallTime>,
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let before = benched.work_counts();
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
-            for _ in 0..*LINK_COUNT {
-                let _ = elapsed! {fork.create_point()?};
+        bench!(|fork| as B, "Create" {
+            use linksneo4j::BACKGROUND_LINKS;
+            let generated = workload::generate(*LINK_COUNT);
+            for (i, &(source, target)) in generated.edges.iter().enumerate() {
+                let next_id = *BACKGROUND_LINKS + i + 1;
+                let _ = elapsed! {
+                    fork.create_point()?;
+                    fork.update(next_id, source, target)?
+                };
             }
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
     });
+    let after = benched.work_counts();
+    COUNTERS.record(id, "Create", after - before);
 }
 
 /// Creates benchmark for Doublets backends on link creation.
 pub fn create_links(c: &mut Criterion) {
+    if !SELECTOR.operation("create") {
+        return;
+    }
     let mut group = c.benchmark_group("Create");
+    group.throughput(Throughput::Elements(*LINK_COUNT as u64));
 
     tri! {
         bench(
@@ -73,6 +97,27 @@ pub fn create_links(c: &mut Criterion) {
             split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap()
         )
     }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Doublets_RocksDB",
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench").unwrap(),
+        );
+    }
 
     group.finish();
 }
@@ -15,39 +15,54 @@ use std::{
     time::{Duration, Instant},
 };
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
 use doublets::{
     mem::{Alloc, FileMapped},
     parts::LinkPart,
     split::{self, DataPart, IndexPart},
     unit, Doublets,
 };
-use linksneo4j::{bench, Benched, Fork, LINK_COUNT};
+use linksneo4j::{
+    bench,
+    counters::{WorkCounted, COUNTERS},
+    selector::SELECTOR,
+    Benched, Exclusive, Fork, RocksDb, Sqlite, LINK_COUNT,
+};
 
 use crate::tri;
 
 /// Runs the delete benchmark on a Doublets backend.
-fn bench<B: Benched + Doublets<usize>>(
+fn bench<B: Benched + Doublets<usize> + WorkCounted>(
     group: &mut BenchmarkGroup<WallTime>,
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let before = benched.work_counts();
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
+        bench!(|fork| as B, "Delete" {
             use linksneo4j::BACKGROUND_LINKS;
-            for _prepare in BACKGROUND_LINKS..BACKGROUND_LINKS + *LINK_COUNT {
+            for _prepare in *BACKGROUND_LINKS..*BACKGROUND_LINKS + *LINK_COUNT {
                 let _ = fork.create_point();
             }
-            for id in (BACKGROUND_LINKS + 1..=BACKGROUND_LINKS + *LINK_COUNT).rev() {
+            for id in (*BACKGROUND_LINKS + 1..=*BACKGROUND_LINKS + *LINK_COUNT).rev() {
                 let _ = elapsed! {fork.delete(id)?};
             }
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
     });
+    let after = benched.work_counts();
+    COUNTERS.record(id, "Delete", after - before);
 }
 
 /// Creates benchmark for Doublets backends on link deletion.
 pub fn delete_links(c: &mut Criterion) {
+    if !SELECTOR.operation("delete") {
+        return;
+    }
     let mut group = c.benchmark_group("Delete");
+    group.throughput(Throughput::Elements(*LINK_COUNT as u64));
 
     tri! {
         bench(
@@ -77,6 +92,27 @@ pub fn delete_links(c: &mut Criterion) {
             split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap()
         )
     }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Doublets_RocksDB",
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench").unwrap(),
+        );
+    }
 
     group.finish();
 }
@@ -13,7 +13,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
 use doublets::data::{Flow, LinksConstants};
 use doublets::{
     mem::{Alloc, FileMapped},
@@ -21,31 +21,46 @@ use doublets::{
     split::{self, DataPart, IndexPart},
     unit, Doublets,
 };
-use linksneo4j::{bench, Benched, Fork};
+use linksneo4j::{
+    bench,
+    counters::{WorkCounted, COUNTERS},
+    selector::SELECTOR,
+    Benched, Exclusive, Fork, RocksDb, Sqlite,
+};
 
 use crate::tri;
 
 /// Runs the each_identity benchmark on a Doublets backend.
-fn bench<B: Benched + Doublets<usize>>(
+fn bench<B: Benched + Doublets<usize> + WorkCounted>(
     group: &mut BenchmarkGroup<WallTime>,
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
     let handler = |_| Flow::Continue;
     let any = LinksConstants::new().any;
+    let before = benched.work_counts();
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
+        bench!(|fork| as B, "Each_Identity" {
             use linksneo4j::BACKGROUND_LINKS;
-            for index in 1..=BACKGROUND_LINKS {
+            for index in 1..=*BACKGROUND_LINKS {
                 elapsed! {fork.each_by([index, any, any], handler)};
             }
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
     });
+    let after = benched.work_counts();
+    COUNTERS.record(id, "Each_Identity", after - before);
 }
 
 /// Creates benchmark for Doublets backends on ID lookup.
 pub fn each_identity(c: &mut Criterion) {
+    if !SELECTOR.operation("each_identity") {
+        return;
+    }
     let mut group = c.benchmark_group("Each_Identity");
+    group.throughput(Throughput::Elements(*linksneo4j::BACKGROUND_LINKS as u64));
 
     tri! {
         bench(
@@ -75,6 +90,27 @@ pub fn each_identity(c: &mut Criterion) {
             split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap()
         )
     }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Doublets_RocksDB",
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench").unwrap(),
+        );
+    }
 
     group.finish();
 }
@@ -13,7 +13,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
 use doublets::data::{Flow, LinksConstants};
 use doublets::{
     mem::{Alloc, FileMapped},
@@ -21,7 +21,7 @@ use doublets::{
     split::{self, DataPart, IndexPart},
     unit, Doublets,
 };
-use linksneo4j::{bench, Benched, Fork};
+use linksneo4j::{bench, selector::SELECTOR, Benched, Exclusive, Fork, RocksDb, Sqlite};
 
 use crate::tri;
 
@@ -31,21 +31,28 @@ fn bench<B: Benched + Doublets<usize>>(
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
     let handler = |_| Flow::Continue;
     let any = LinksConstants::new().any;
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
+        bench!(|fork| as B, "Each_Outgoing" {
             use linksneo4j::BACKGROUND_LINKS;
-            for index in 1..=BACKGROUND_LINKS {
+            for index in 1..=*BACKGROUND_LINKS {
                 let _ = elapsed! {fork.each_by([any, index, any], handler)};
             }
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
     });
 }
 
 /// Creates benchmark for Doublets backends on source index lookup.
 pub fn each_outgoing(c: &mut Criterion) {
+    if !SELECTOR.operation("each_outgoing") {
+        return;
+    }
     let mut group = c.benchmark_group("Each_Outgoing");
+    group.throughput(Throughput::Elements(*linksneo4j::BACKGROUND_LINKS as u64));
 
     tri! {
         bench(
@@ -75,6 +82,27 @@ pub fn each_outgoing(c: &mut Criterion) {
             split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap()
         )
     }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Doublets_RocksDB",
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench").unwrap(),
+        );
+    }
 
     group.finish();
 }
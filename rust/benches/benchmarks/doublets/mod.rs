@@ -23,12 +23,15 @@
 //! - `Doublets_United_NonVolatile` - File-mapped unit storage
 //! - `Doublets_Split_Volatile` - In-memory split storage (separate data/index)
 //! - `Doublets_Split_NonVolatile` - File-mapped split storage
+//! - `Doublets_RocksDB` - Embedded LSM-tree storage with source/target secondary indexes
 
+mod bulk;
 mod create;
 mod delete;
 pub mod each;
 mod update;
 
+pub use bulk::{bulk_create, bulk_delete};
 pub use create::create_links;
 pub use delete::delete_links;
 pub use each::*;
@@ -6,7 +6,10 @@
 //!
 //! Doublets updates links by:
 //! - Looking up link by ID (O(1) array access)
-//! - Updating source/target values in storage
+//! - Updating source/target values in storage -- both updates per id draw
+//!   their `source`/`target` from [`linksneo4j::workload::generate`] rather
+//!   than the degenerate `(0, 0)` then `(id, id)` self-loop, so the
+//!   re-indexing below is exercised against realistic, non-uniform degree
 //! - Re-indexing in source and target trees if values changed
 //! - Time complexity: O(log n) for index updates
 
@@ -15,38 +18,56 @@ use std::{
     time::{Duration, Instant},
 };
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
 use doublets::{
     mem::{Alloc, FileMapped},
     parts::LinkPart,
     split::{self, DataPart, IndexPart},
     unit, Doublets,
 };
-use linksneo4j::{bench, Benched, Fork, LINK_COUNT};
+use linksneo4j::{
+    bench,
+    counters::{WorkCounted, COUNTERS},
+    selector::SELECTOR,
+    workload, Benched, Exclusive, Fork, RocksDb, Sqlite, LINK_COUNT,
+};
 
 use crate::tri;
 
 /// Runs the update benchmark on a Doublets backend.
-fn bench<B: Benched + Doublets<usize>>(
+fn bench<B: Benched + Doublets<usize> + WorkCounted>(
     group: &mut BenchmarkGroup<WallTime>,
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let before = benched.work_counts();
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
+        bench!(|fork| as B, "Update" {
             use linksneo4j::BACKGROUND_LINKS;
-            let start_id = if BACKGROUND_LINKS > *LINK_COUNT { BACKGROUND_LINKS - *LINK_COUNT + 1 } else { 1 };
-            for id in start_id..=BACKGROUND_LINKS {
-                let _ = elapsed! {fork.update(id, 0, 0)?};
-                let _ = elapsed! {fork.update(id, id, id)?};
+            let start_id = if *BACKGROUND_LINKS > *LINK_COUNT { *BACKGROUND_LINKS - *LINK_COUNT + 1 } else { 1 };
+            let generated = workload::generate(2 * (*BACKGROUND_LINKS - start_id + 1));
+            for (i, id) in (start_id..=*BACKGROUND_LINKS).enumerate() {
+                let (first_source, first_target) = generated.edges[2 * i];
+                let (second_source, second_target) = generated.edges[2 * i + 1];
+                let _ = elapsed! {fork.update(id, first_source, first_target)?};
+                let _ = elapsed! {fork.update(id, second_source, second_target)?};
             }
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
     });
+    let after = benched.work_counts();
+    COUNTERS.record(id, "Update", after - before);
 }
 
 /// Creates benchmark for Doublets backends on link updates.
 pub fn update_links(c: &mut Criterion) {
+    if !SELECTOR.operation("update") {
+        return;
+    }
     let mut group = c.benchmark_group("Update");
+    group.throughput(Throughput::Elements(2 * *LINK_COUNT as u64));
 
     tri! {
         bench(
@@ -76,6 +97,27 @@ pub fn update_links(c: &mut Criterion) {
             split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap()
         )
     }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Doublets_RocksDB",
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench").unwrap(),
+        );
+    }
 
     group.finish();
 }
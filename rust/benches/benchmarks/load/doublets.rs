@@ -0,0 +1,189 @@
+//! Doublets side of the closed-loop load test -- see [`super`]. All
+//! backends share the same mixed create/each/update tick the
+//! concurrent-throughput benchmark's `run_op` cycles through, but here it's
+//! paced onto [`linksneo4j::load::run`]'s fixed-rate schedule instead of
+//! being hammered as fast as possible.
+//!
+//! [`run_concurrent_load_test`] reuses the same mixed tick, but spreads it
+//! over a worker pool sharing one backend behind a `Mutex` -- the same
+//! sharing `benches/benchmarks/concurrent`
+//! uses -- via [`linksneo4j::load::run_concurrent`].
+
+use std::{
+    alloc::Global,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use doublets::{
+    data::{Flow, LinksConstants},
+    mem::{Alloc, FileMapped},
+    parts::LinkPart,
+    split::{self, DataPart, IndexPart},
+    unit, Doublets,
+};
+use linksneo4j::{
+    load,
+    load::{ConcurrentLoadConfig, LoadConfig},
+    selector::SELECTOR,
+    Benched, Exclusive, Sqlite,
+};
+
+use crate::tri;
+
+/// One mixed create/read/update tick, cycling by a counter the same way
+/// the concurrent-throughput benchmark's `run_op` does.
+fn tick<B: Doublets<usize>>(store: &mut B, any: usize, counter: &mut usize) {
+    let handler = |_| Flow::Continue;
+    let id = *counter % 10_000 + 1;
+    match *counter % 3 {
+        0 => {
+            let _ = store.create_point();
+        }
+        1 => {
+            store.each_by([any, id, any], handler);
+        }
+        _ => {
+            let _ = store.update(id, id, id);
+        }
+    }
+    *counter += 1;
+}
+
+/// The same mixed tick as [`tick`], but reporting whether its operation
+/// succeeded so [`run_concurrent_load_test`] can track an error rate.
+fn tick_reporting<B: Doublets<usize>>(store: &mut B, any: usize, counter: usize) -> bool {
+    let handler = |_| Flow::Continue;
+    let id = counter % 10_000 + 1;
+    match counter % 3 {
+        0 => store.create_point().is_ok(),
+        1 => {
+            store.each_by([any, id, any], handler);
+            true
+        }
+        _ => store.update(id, id, id).is_ok(),
+    }
+}
+
+fn run<B: Benched + Doublets<usize>>(id: &str, mut benched: B, config: &LoadConfig) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let any = LinksConstants::new().any;
+    let mut counter = 0usize;
+    load::run(id, config, || tick(&mut benched, any, &mut counter)).print();
+}
+
+fn run_concurrent<B>(id: &str, benched: B, config: &ConcurrentLoadConfig)
+where
+    B: Benched + Doublets<usize> + Send,
+{
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let any = LinksConstants::new().any;
+    let shared = Arc::new(Mutex::new(benched));
+    let counter = AtomicUsize::new(0);
+
+    load::run_concurrent(id, config, || {
+        let offset = counter.fetch_add(1, Ordering::Relaxed);
+        let mut store = shared.lock().expect("store lock poisoned");
+        tick_reporting(&mut *store, any, offset)
+    })
+    .print();
+}
+
+/// Runs the load test against every Doublets and SQLite backend.
+pub fn run_load_test(config: &LoadConfig) {
+    tri! {
+        run(
+            "Doublets_United_Volatile",
+            unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(()).unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run(
+            "Doublets_United_NonVolatile",
+            unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links").unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run(
+            "Doublets_Split_Volatile",
+            split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(()).unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run(
+            "Doublets_Split_NonVolatile",
+            split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run(
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run(
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+            config,
+        )
+    }
+}
+
+/// Runs the concurrent throughput harness against every Doublets and
+/// SQLite backend.
+pub fn run_concurrent_load_test(config: &ConcurrentLoadConfig) {
+    tri! {
+        run_concurrent(
+            "Doublets_United_Volatile",
+            unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(()).unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run_concurrent(
+            "Doublets_United_NonVolatile",
+            unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links").unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run_concurrent(
+            "Doublets_Split_Volatile",
+            split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(()).unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run_concurrent(
+            "Doublets_Split_NonVolatile",
+            split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup(("split_index.links", "split_data.links")).unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run_concurrent(
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+            config,
+        )
+    }
+    tri! {
+        run_concurrent(
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+            config,
+        )
+    }
+}
@@ -0,0 +1,61 @@
+//! # Closed-Loop Load Test
+//!
+//! Unlike every other benchmark in this crate, which lets Criterion pick
+//! its own iteration count, this mode is opted into via
+//! [`linksneo4j::load::LoadConfig::from_env`]: set
+//! `BENCHMARK_LOAD_TARGET_OPS` and `BENCHMARK_LOAD_DURATION_SECS` to replay
+//! a mixed create/each/update workload against every selected backend at
+//! that sustained rate for that long. Each backend's achieved rate and
+//! coordinated-omission-corrected p50/p95/p99 latency (see
+//! [`linksneo4j::load`]) are printed directly rather than folded into a
+//! Criterion report. `bench.rs`'s `main` calls [`run_load_test`] after both
+//! `criterion_group!`s finish; it's a no-op when the env vars aren't set.
+//!
+//! [`run_concurrent_load_test`] is the sibling worker-pool mode: opted into
+//! separately via [`linksneo4j::load::ConcurrentLoadConfig::from_env`]
+//! (`BENCHMARK_WORKERS`, `BENCHMARK_DURATION_SECS`, optional
+//! `BENCHMARK_TARGET_OPS`), it spreads the same mixed workload over a
+//! worker pool sharing one backend instead of pacing a single client, and
+//! its report adds an error count.
+//!
+//! ## Module Structure
+//!
+//! - **[`neo4j`]** - Swept against the Neo4j backends.
+//! - **[`doublets`]** - Swept against the Doublets and SQLite backends.
+
+mod doublets;
+mod neo4j;
+
+use linksneo4j::{
+    load::{ConcurrentLoadConfig, LoadConfig},
+    selector::SELECTOR,
+};
+
+/// Runs the closed-loop load test against every selected backend, if
+/// [`LoadConfig::from_env`] says it was requested. No-op otherwise.
+pub fn run_load_test() {
+    let Some(config) = LoadConfig::from_env() else {
+        return;
+    };
+    if !SELECTOR.operation("load_test") {
+        return;
+    }
+
+    self::doublets::run_load_test(&config);
+    self::neo4j::run_load_test(&config);
+}
+
+/// Runs the concurrent throughput harness against every selected backend,
+/// if [`ConcurrentLoadConfig::from_env`] says it was requested. No-op
+/// otherwise.
+pub fn run_concurrent_load_test() {
+    let Some(config) = ConcurrentLoadConfig::from_env() else {
+        return;
+    };
+    if !SELECTOR.operation("concurrent_load_test") {
+        return;
+    }
+
+    self::doublets::run_concurrent_load_test(&config);
+    self::neo4j::run_concurrent_load_test(&config);
+}
@@ -0,0 +1,114 @@
+//! Neo4j side of the closed-loop load test -- see [`super`]. Covers
+//! `Neo4j_NonTransaction` and `Neo4j_Batched`; `Neo4j_Transaction` is not
+//! run here.
+//!
+//! [`run_concurrent_load_test`] only covers `Neo4j_NonTransaction`:
+//! `Neo4j_Batched` buffers pending ops in `&mut self` between flushes, so
+//! sharing one `Batched` across worker threads would need a lock around
+//! every op, serializing the very concurrency this harness exists to
+//! measure. `Client`'s own calls (`execute_cypher`) open a fresh `TcpStream`
+//! per request and only touch `&self` atomic counters, so workers share one
+//! `Client` directly rather than each opening its own connection the way
+//! `benches/benchmarks/concurrent::neo4j` does.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use doublets::{data::LinksConstants, Doublets};
+use linksneo4j::{
+    connect, load,
+    load::{ConcurrentLoadConfig, LoadConfig},
+    selector::SELECTOR, Batched, Benched, Client, Exclusive, NEO4J_BATCH_SIZE,
+};
+use serde_json::json;
+
+use crate::tri;
+
+/// One mixed create/read/update tick, cycling by a counter the same way
+/// the Doublets side's `tick` (`load::doublets`) does.
+fn tick<B: Doublets<usize>>(store: &mut B, any: usize, counter: &mut usize) {
+    let handler = |_| doublets::data::Flow::Continue;
+    let id = *counter % 10_000 + 1;
+    match *counter % 3 {
+        0 => {
+            let _ = store.create_point();
+        }
+        1 => {
+            store.each_by([any, id, any], handler);
+        }
+        _ => {
+            let _ = store.update(id, id, id);
+        }
+    }
+    *counter += 1;
+}
+
+fn run<B: Benched + Doublets<usize>>(id: &str, mut benched: B, config: &LoadConfig) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let any = LinksConstants::new().any;
+    let mut counter = 0usize;
+    load::run(id, config, || tick(&mut benched, any, &mut counter)).print();
+}
+
+/// Runs the load test against `Neo4j_NonTransaction` and `Neo4j_Batched`.
+pub fn run_load_test(config: &LoadConfig) {
+    tri! {
+        run(
+            "Neo4j_NonTransaction",
+            Exclusive::<Client<usize>>::setup(()).unwrap(),
+            config,
+        )
+    }
+    tri! {
+        let client = connect().unwrap();
+        run(
+            "Neo4j_Batched",
+            Exclusive::<Batched<'_, usize>>::setup((&client, *NEO4J_BATCH_SIZE)).unwrap(),
+            config,
+        );
+    }
+}
+
+/// One mixed create/read/update request against a shared `Client`, cycling
+/// by a counter the same way `concurrent::neo4j`'s `run_op` does, but
+/// reporting whether the request succeeded.
+fn run_op(client: &Client<usize>, counter: usize) -> bool {
+    let id = counter % 10_000 + 1;
+    match counter % 3 {
+        0 => client
+            .execute_cypher(
+                "CREATE (l:Link {id: $id, source: 0, target: 0})",
+                Some(json!({ "id": id })),
+            )
+            .is_ok(),
+        1 => client
+            .execute_cypher(
+                "MATCH (l:Link) WHERE l.source = $source RETURN l.id",
+                Some(json!({ "source": id })),
+            )
+            .is_ok(),
+        _ => client
+            .execute_cypher(
+                "MATCH (l:Link {id: $id}) SET l.source = $id, l.target = $id",
+                Some(json!({ "id": id })),
+            )
+            .is_ok(),
+    }
+}
+
+/// Runs the concurrent throughput harness against `Neo4j_NonTransaction`.
+pub fn run_concurrent_load_test(config: &ConcurrentLoadConfig) {
+    if !SELECTOR.backend("Neo4j_NonTransaction") {
+        return;
+    }
+    tri! {
+        let client = connect().unwrap();
+        let counter = AtomicUsize::new(0);
+        load::run_concurrent("Neo4j_NonTransaction", config, || {
+            let offset = counter.fetch_add(1, Ordering::Relaxed);
+            run_op(&client, offset)
+        })
+        .print();
+    }
+}
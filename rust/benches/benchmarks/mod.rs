@@ -22,6 +22,14 @@
 //! | `each_concrete` | Query by source+target `[*, src, tgt]`         | Composite index lookup              |
 //! | `each_outgoing` | Query by source `[*, src, *]`                  | Source index lookup                 |
 //! | `each_incoming` | Query by target `[*, *, tgt]`                  | Target index lookup                 |
+//! | `bulk_create`   | Insert links in configurable batches           | Amortized write throughput          |
+//! | `bulk_delete`   | Remove links in configurable batches           | Amortized delete throughput         |
+//! | `scaling_curve` | Creates swept across database sizes            | Fixed overhead vs. per-link cost    |
+//! | `graph_traversal` | Multi-hop reachability over a seeded graph   | Chained index lookups vs. one query |
+//! | `snapshot_export` | Encode a full link set's columns to bytes    | Storage-level serialization cost    |
+//! | `snapshot_import` | Decode an encoded snapshot back to columns   | Storage-level deserialization cost  |
+//! | `load_test`     | Mixed create/each/update at a fixed target rate | Sustained steady-state behavior, not Criterion's own iteration count (see [`load`]) |
+//! | `concurrent_load_test` | Mixed create/each/update over a worker pool, fixed duration | Throughput, tail latency and error rate under concurrent load, at an optional target rate (see [`load`]) |
 //!
 //! ## Storage Backends Tested
 //!
@@ -31,14 +39,52 @@
 //! - `Doublets_Split_Volatile` - In-memory split storage (separate data/index)
 //! - `Doublets_Split_NonVolatile` - File-mapped split storage
 //!
-//! ### Neo4j (2 variants)
+//! ### Neo4j (4 variants)
 //! - `Neo4j_NonTransaction` - Direct HTTP API calls
 //! - `Neo4j_Transaction` - Transaction wrapper (same underlying implementation)
+//! - `Neo4j_Batched` - Buffers create/update/delete ops, flushes as batched `UNWIND` queries
+//! - `Neo4j_Concurrent/{level}` - `create_links`/`delete_links` only; replays the
+//!   workload through a bounded job queue feeding `level` worker threads, each
+//!   with its own connection (see [`linksneo4j::concurrency_levels`])
+//!
+//! ### SQLite (2 variants)
+//! - `Sqlite_Volatile` - In-memory (`:memory:`) single-table store
+//! - `Sqlite_NonVolatile` - File-backed, same schema
+//!
+//! ### RocksDB (1 variant)
+//! - `Doublets_RocksDB` - Embedded LSM-tree store with `by_source`/`by_target`
+//!   secondary-index column families
 
+pub mod concurrent;
 pub mod doublets;
+pub mod load;
 pub mod neo4j;
+pub mod scaling;
+pub mod snapshot;
+pub mod traversal;
+
+// Re-export the concurrent-throughput benchmarks
+pub use concurrent::{doublets_concurrent_throughput, neo4j_concurrent_throughput};
+
+// Re-export the scaling-curve benchmarks
+pub use scaling::{doublets_scaling_curve, neo4j_scaling_curve};
+
+// Re-export the graph-traversal benchmarks
+pub use traversal::{doublets_graph_traversal, neo4j_graph_traversal};
+
+// Re-export the snapshot export/import benchmarks
+pub use snapshot::{
+    doublets_snapshot_export, doublets_snapshot_import, neo4j_snapshot_export,
+    neo4j_snapshot_import,
+};
+
+// Re-export the closed-loop load test and its worker-pool sibling -- not
+// `criterion_group!` members, see `load`'s module doc.
+pub use load::{run_concurrent_load_test, run_load_test};
 
 // Re-export all Neo4j benchmarks with neo4j_ prefix
+pub use neo4j::bulk_create as neo4j_bulk_create;
+pub use neo4j::bulk_delete as neo4j_bulk_delete;
 pub use neo4j::create_links as neo4j_create_links;
 pub use neo4j::delete_links as neo4j_delete_links;
 pub use neo4j::each_all as neo4j_each_all;
@@ -49,6 +95,8 @@ pub use neo4j::each_outgoing as neo4j_each_outgoing;
 pub use neo4j::update_links as neo4j_update_links;
 
 // Re-export all Doublets benchmarks with doublets_ prefix
+pub use self::doublets::bulk_create as doublets_bulk_create;
+pub use self::doublets::bulk_delete as doublets_bulk_delete;
 pub use self::doublets::create_links as doublets_create_links;
 pub use self::doublets::delete_links as doublets_delete_links;
 pub use self::doublets::each_all as doublets_each_all;
@@ -0,0 +1,148 @@
+//! # Neo4j Bulk Write Benchmark
+//!
+//! Measures Neo4j write throughput when `batch` links are created (or
+//! deleted) per HTTP request instead of one round-trip per link, using
+//! Cypher's `UNWIND` to amortize the request overhead across the batch:
+//!
+//! ```cypher
+//! UNWIND $rows AS r CREATE (l:Link {id: r.id, source: r.source, target: r.target})
+//! UNWIND $ids AS id MATCH (l:Link {id: id}) DELETE l
+//! ```
+//!
+//! Batch size is swept across [`linksneo4j::batch_sizes`] (configurable via
+//! `BENCHMARK_BATCH_SIZES`) so the throughput reported by each
+//! `bench_function` is directly comparable to the per-link `create_links`/
+//! `delete_links` benchmarks at `batch = 1`.
+//!
+//! This drives `fetch_next_id`/`execute_cypher`, inherent methods specific
+//! to `Client`/`Transaction`, so only those two backends run here; Sqlite is
+//! benchmarked under the same `Bulk_Create`/`Bulk_Delete` groups by
+//! `benches/benchmarks/doublets/bulk.rs`, which drives it through the
+//! `Doublets` trait loop instead.
+
+use std::time::{Duration, Instant};
+
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
+use doublets::Doublets;
+use linksneo4j::{
+    batch_sizes, bench, connect, selector::SELECTOR, Benched, Client, Exclusive, Fork, Transaction,
+};
+use serde_json::json;
+
+use crate::tri;
+
+/// Runs the bulk create benchmark on a Neo4j backend, once per batch size.
+fn bench_create<B: Benched + Doublets<usize>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: &str,
+    mut benched: B,
+) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    for batch in batch_sizes() {
+        if !SELECTOR.size(batch) {
+            continue;
+        }
+        group.throughput(Throughput::Elements(batch as u64));
+        group.bench_function(format!("{id}/{batch}"), |bencher| {
+            bench!(|fork| as B, "Bulk_Create" {
+                let rows: Vec<_> = (0..batch)
+                    .map(|_| {
+                        let next_id = fork.fetch_next_id();
+                        json!({ "id": next_id, "source": next_id, "target": next_id })
+                    })
+                    .collect();
+
+                let _ = elapsed! {
+                    fork.execute_cypher(
+                        "UNWIND $rows AS r CREATE (l:Link {id: r.id, source: r.source, target: r.target})",
+                        Some(json!({ "rows": rows })),
+                    )
+                };
+            })(bencher, &mut benched, id);
+        });
+    }
+}
+
+/// Runs the bulk delete benchmark on a Neo4j backend, once per batch size.
+fn bench_delete<B: Benched + Doublets<usize>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: &str,
+    mut benched: B,
+) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    for batch in batch_sizes() {
+        if !SELECTOR.size(batch) {
+            continue;
+        }
+        group.throughput(Throughput::Elements(batch as u64));
+        group.bench_function(format!("{id}/{batch}"), |bencher| {
+            bench!(|fork| as B, "Bulk_Delete" {
+                // Prepare: bulk-create the links this iteration will delete.
+                let ids: Vec<_> = (0..batch).map(|_| fork.fetch_next_id()).collect();
+                let rows: Vec<_> = ids
+                    .iter()
+                    .map(|&row_id| json!({ "id": row_id, "source": row_id, "target": row_id }))
+                    .collect();
+                let _ = fork.execute_cypher(
+                    "UNWIND $rows AS r CREATE (l:Link {id: r.id, source: r.source, target: r.target})",
+                    Some(json!({ "rows": rows })),
+                );
+
+                let _ = elapsed! {
+                    fork.execute_cypher(
+                        "UNWIND $ids AS id MATCH (l:Link {id: id}) DELETE l",
+                        Some(json!({ "ids": ids })),
+                    )
+                };
+            })(bencher, &mut benched, id);
+        });
+    }
+}
+
+/// Creates benchmark for Neo4j backends on batched (`UNWIND`) link creation.
+pub fn bulk_create(c: &mut Criterion) {
+    if !SELECTOR.operation("bulk_create") {
+        return;
+    }
+    let mut group = c.benchmark_group("Bulk_Create");
+
+    tri! {
+        bench_create(&mut group, "Neo4j_NonTransaction", Exclusive::<Client<usize>>::setup(()).unwrap());
+    }
+    tri! {
+        let client = connect().unwrap();
+        bench_create(
+            &mut group,
+            "Neo4j_Transaction",
+            Exclusive::<Transaction<'_, usize>>::setup(&client).unwrap(),
+        );
+    }
+
+    group.finish();
+}
+
+/// Creates benchmark for Neo4j backends on batched (`UNWIND`) link deletion.
+pub fn bulk_delete(c: &mut Criterion) {
+    if !SELECTOR.operation("bulk_delete") {
+        return;
+    }
+    let mut group = c.benchmark_group("Bulk_Delete");
+
+    tri! {
+        bench_delete(&mut group, "Neo4j_NonTransaction", Exclusive::<Client<usize>>::setup(()).unwrap());
+    }
+    tri! {
+        let client = connect().unwrap();
+        bench_delete(
+            &mut group,
+            "Neo4j_Transaction",
+            Exclusive::<Transaction<'_, usize>>::setup(&client).unwrap(),
+        );
+    }
+
+    group.finish();
+}
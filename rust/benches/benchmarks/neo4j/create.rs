@@ -8,38 +8,139 @@
 //! ```cypher
 //! CREATE (l:Link {id: $id, source: 0, target: 0})
 //! ```
+//! followed by
+//! ```cypher
+//! MATCH (l:Link {id: $id}) SET l.source = $source, l.target = $target
+//! ```
+//! with `source`/`target` coming from [`linksneo4j::workload::generate`]
+//! rather than the degenerate `(0, 0)` self-loop, so the property update
+//! above is exercised against realistic, non-uniform degree.
 //!
 //! - Makes HTTP request to `/db/neo4j/tx/commit`
 //! - Neo4j allocates node, sets properties
 //! - Updates indexes on id, source, target
 //! - Time complexity: O(log n) + network overhead
+//!
+//! `Neo4j_Concurrent/{level}`, for each of
+//! [`linksneo4j::concurrency_levels`], replays the same workload through a
+//! bounded job queue feeding `level` worker threads (each with its own
+//! `connect()`ed `Client`), surfacing throughput scaling the single-client
+//! variants above can't measure.
 
 use std::time::{Duration, Instant};
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
+use crossbeam::{channel, thread};
 use doublets::Doublets;
-use linksneo4j::{bench, connect, Benched, Client, Exclusive, Fork, Transaction, LINK_COUNT};
+use linksneo4j::{
+    bench, connect,
+    counters::{WorkCounted, COUNTERS},
+    selector::SELECTOR,
+    workload, Batched, Benched, Client, Exclusive, Fork, Sql, Sqlite, Transaction, LINK_COUNT,
+    NEO4J_BATCH_SIZE,
+};
+use serde_json::json;
 
 use crate::tri;
 
 /// Runs the create benchmark on a Neo4j backend.
-fn bench<B: Benched + Doublets<usize>>(
+fn bench<B: Benched + Doublets<usize> + WorkCounted>(
     group: &mut BenchmarkGroup<WallTime>,
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let before = benched.work_counts();
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
-            for _ in 0..*LINK_COUNT {
-                let _ = elapsed! {fork.create_point()?};
+        bench!(|fork| as B, "Create" {
+            use linksneo4j::BACKGROUND_LINKS;
+            let generated = workload::generate(*LINK_COUNT);
+            for (i, &(source, target)) in generated.edges.iter().enumerate() {
+                let next_id = *BACKGROUND_LINKS + i + 1;
+                let _ = elapsed! {
+                    fork.create_point()?;
+                    fork.update(next_id, source, target)?
+                };
+            }
+        })(bencher, &mut benched, id);
+    });
+    let after = benched.work_counts();
+    COUNTERS.record(id, "Create", after - before);
+}
+
+/// Runs the create benchmark on a worker pool of `level` Neo4j clients: a
+/// bounded job queue feeds workload-generated `(id, source, target)` rows
+/// to `level` worker threads, each holding its own `connect()`ed `Client`,
+/// so the timed region measures how the HTTP server's throughput scales
+/// with client concurrency rather than a single connection's round-trip
+/// latency.
+fn bench_concurrent(group: &mut BenchmarkGroup<WallTime>, level: usize) {
+    let id = format!("Neo4j_Concurrent/{level}");
+    if !SELECTOR.backend("Neo4j_Concurrent") || !SELECTOR.size(level) {
+        return;
+    }
+    group.throughput(Throughput::Elements(*LINK_COUNT as u64));
+    group.bench_function(&id, |bencher| {
+        bencher.iter_custom(|iters| {
+            let mut duration = Duration::ZERO;
+            for _ in 0..iters {
+                let Ok(client) = connect::<usize>() else {
+                    continue;
+                };
+                let _ = client.create_table();
+                for background_id in 1..=*linksneo4j::BACKGROUND_LINKS {
+                    let _ = client.execute_cypher(
+                        "CREATE (l:Link {id: $id, source: $id, target: $id})",
+                        Some(json!({ "id": background_id })),
+                    );
+                }
+
+                let generated = workload::generate(*LINK_COUNT);
+                let (sender, receiver) = channel::bounded(level * 4);
+
+                let start = Instant::now();
+                thread::scope(|scope| {
+                    for _ in 0..level {
+                        let receiver = receiver.clone();
+                        scope.spawn(move |_| {
+                            let Ok(worker) = connect::<usize>() else {
+                                return;
+                            };
+                            while let Ok((row_id, source, target)) = receiver.recv() {
+                                let _ = worker.execute_cypher(
+                                    "CREATE (l:Link {id: $id, source: $source, target: $target})",
+                                    Some(json!({ "id": row_id, "source": source, "target": target })),
+                                );
+                            }
+                        });
+                    }
+
+                    use linksneo4j::BACKGROUND_LINKS;
+                    for (i, &(source, target)) in generated.edges.iter().enumerate() {
+                        let row_id = *BACKGROUND_LINKS + i + 1;
+                        let _ = sender.send((row_id, source, target));
+                    }
+                    drop(sender);
+                })
+                .expect("worker threads panicked");
+                duration += start.elapsed();
+
+                let _ = client.drop_table();
             }
-        })(bencher, &mut benched);
+            duration
+        });
     });
 }
 
 /// Creates benchmark for Neo4j backends on link creation.
 pub fn create_links(c: &mut Criterion) {
+    if !SELECTOR.operation("create") {
+        return;
+    }
     let mut group = c.benchmark_group("Create");
+    group.throughput(Throughput::Elements(*LINK_COUNT as u64));
 
     tri! {
         bench(&mut group, "Neo4j_NonTransaction", Exclusive::<Client<usize>>::setup(()).unwrap());
@@ -52,6 +153,33 @@ pub fn create_links(c: &mut Criterion) {
             Exclusive::<Transaction<'_, usize>>::setup(&client).unwrap(),
         );
     }
+    tri! {
+        let client = connect().unwrap();
+        bench(
+            &mut group,
+            "Neo4j_Batched",
+            Exclusive::<Batched<'_, usize>>::setup((&client, *NEO4J_BATCH_SIZE)).unwrap(),
+        );
+    }
+    for level in linksneo4j::concurrency_levels() {
+        tri! {
+            bench_concurrent(&mut group, level);
+        }
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
 
     group.finish();
 }
@@ -13,37 +13,122 @@
 //! - Neo4j finds node by indexed id property
 //! - Removes node and updates indexes
 //! - Time complexity: O(log n) + network overhead
+//!
+//! `Neo4j_Concurrent/{level}`, for each of
+//! [`linksneo4j::concurrency_levels`], replays the same workload through a
+//! bounded job queue feeding `level` worker threads (each with its own
+//! `connect()`ed `Client`), surfacing throughput scaling the single-client
+//! variants above can't measure.
 
 use std::time::{Duration, Instant};
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
+use crossbeam::{channel, thread};
 use doublets::Doublets;
-use linksneo4j::{bench, connect, Benched, Client, Exclusive, Fork, Transaction, LINK_COUNT};
+use linksneo4j::{
+    bench, connect,
+    counters::{WorkCounted, COUNTERS},
+    selector::SELECTOR,
+    Batched, Benched, Client, Exclusive, Fork, Sql, Sqlite, Transaction, LINK_COUNT,
+    NEO4J_BATCH_SIZE,
+};
+use serde_json::json;
 
 use crate::tri;
 
 /// Runs the delete benchmark on a Neo4j backend.
-fn bench<B: Benched + Doublets<usize>>(
+fn bench<B: Benched + Doublets<usize> + WorkCounted>(
     group: &mut BenchmarkGroup<WallTime>,
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let before = benched.work_counts();
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
+        bench!(|fork| as B, "Delete" {
             use linksneo4j::BACKGROUND_LINKS;
-            for _prepare in BACKGROUND_LINKS..BACKGROUND_LINKS + *LINK_COUNT {
+            for _prepare in *BACKGROUND_LINKS..*BACKGROUND_LINKS + *LINK_COUNT {
                 let _ = fork.create_point();
             }
-            for id in (BACKGROUND_LINKS + 1..=BACKGROUND_LINKS + *LINK_COUNT).rev() {
+            for id in (*BACKGROUND_LINKS + 1..=*BACKGROUND_LINKS + *LINK_COUNT).rev() {
                 let _ = elapsed! {fork.delete(id)?};
             }
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
+    });
+    let after = benched.work_counts();
+    COUNTERS.record(id, "Delete", after - before);
+}
+
+/// Runs the delete benchmark on a worker pool of `level` Neo4j clients: the
+/// links to delete are bulk-created serially (untimed) before the timed
+/// region feeds their ids through a bounded job queue to `level` worker
+/// threads, each holding its own `connect()`ed `Client`.
+fn bench_concurrent(group: &mut BenchmarkGroup<WallTime>, level: usize) {
+    let id = format!("Neo4j_Concurrent/{level}");
+    if !SELECTOR.backend("Neo4j_Concurrent") || !SELECTOR.size(level) {
+        return;
+    }
+    group.throughput(Throughput::Elements(*LINK_COUNT as u64));
+    group.bench_function(&id, |bencher| {
+        bencher.iter_custom(|iters| {
+            let mut duration = Duration::ZERO;
+            for _ in 0..iters {
+                let Ok(client) = connect::<usize>() else {
+                    continue;
+                };
+                let _ = client.create_table();
+                let total = *linksneo4j::BACKGROUND_LINKS + *LINK_COUNT;
+                for prepare_id in 1..=total {
+                    let _ = client.execute_cypher(
+                        "CREATE (l:Link {id: $id, source: $id, target: $id})",
+                        Some(json!({ "id": prepare_id })),
+                    );
+                }
+
+                let (sender, receiver) = channel::bounded(level * 4);
+
+                let start = Instant::now();
+                thread::scope(|scope| {
+                    for _ in 0..level {
+                        let receiver = receiver.clone();
+                        scope.spawn(move |_| {
+                            let Ok(worker) = connect::<usize>() else {
+                                return;
+                            };
+                            while let Ok(row_id) = receiver.recv() {
+                                let _ = worker.execute_cypher(
+                                    "MATCH (l:Link {id: $id}) DELETE l",
+                                    Some(json!({ "id": row_id })),
+                                );
+                            }
+                        });
+                    }
+
+                    use linksneo4j::BACKGROUND_LINKS;
+                    for row_id in (*BACKGROUND_LINKS + 1..=total).rev() {
+                        let _ = sender.send(row_id);
+                    }
+                    drop(sender);
+                })
+                .expect("worker threads panicked");
+                duration += start.elapsed();
+
+                let _ = client.drop_table();
+            }
+            duration
+        });
     });
 }
 
 /// Creates benchmark for Neo4j backends on link deletion.
 pub fn delete_links(c: &mut Criterion) {
+    if !SELECTOR.operation("delete") {
+        return;
+    }
     let mut group = c.benchmark_group("Delete");
+    group.throughput(Throughput::Elements(*LINK_COUNT as u64));
 
     tri! {
         bench(&mut group, "Neo4j_NonTransaction", Exclusive::<Client<usize>>::setup(()).unwrap());
@@ -56,6 +141,33 @@ pub fn delete_links(c: &mut Criterion) {
             Exclusive::<Transaction<'_, usize>>::setup(&client).unwrap(),
         );
     }
+    tri! {
+        let client = connect().unwrap();
+        bench(
+            &mut group,
+            "Neo4j_Batched",
+            Exclusive::<Batched<'_, usize>>::setup((&client, *NEO4J_BATCH_SIZE)).unwrap(),
+        );
+    }
+    for level in linksneo4j::concurrency_levels() {
+        tri! {
+            bench_concurrent(&mut group, level);
+        }
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
 
     group.finish();
 }
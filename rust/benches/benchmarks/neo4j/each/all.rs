@@ -14,10 +14,10 @@
 
 use std::time::{Duration, Instant};
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
 use doublets::data::{Flow, LinkType};
 use doublets::Doublets;
-use linksneo4j::{bench, connect, Benched, Client, Exclusive, Fork, Transaction};
+use linksneo4j::{bench, connect, selector::SELECTOR, Benched, Client, Exclusive, Fork, Sqlite, Transaction};
 
 use crate::tri;
 
@@ -27,17 +27,24 @@ fn bench<T: LinkType, B: Benched + Doublets<T>>(
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
     let handler = |_| Flow::Continue;
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
+        bench!(|fork| as B, "Each_All" {
             let _ = elapsed! { fork.each(handler) };
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
     });
 }
 
 /// Creates benchmark for Neo4j backends on full table scan.
 pub fn each_all(c: &mut Criterion) {
+    if !SELECTOR.operation("each_all") {
+        return;
+    }
     let mut group = c.benchmark_group("Each_All");
+    group.throughput(Throughput::Elements(*linksneo4j::BACKGROUND_LINKS as u64));
 
     tri! {
         bench(&mut group, "Neo4j_NonTransaction", Exclusive::<Client<usize>>::setup(()).unwrap());
@@ -50,6 +57,20 @@ pub fn each_all(c: &mut Criterion) {
             Exclusive::<Transaction<'_, usize>>::setup(&client).unwrap(),
         );
     }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
 
     group.finish();
 }
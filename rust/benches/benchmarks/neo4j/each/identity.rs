@@ -14,34 +14,49 @@
 
 use std::time::{Duration, Instant};
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
 use doublets::data::{Flow, LinksConstants};
 use doublets::Doublets;
-use linksneo4j::{bench, connect, Benched, Client, Exclusive, Fork, Transaction};
+use linksneo4j::{
+    bench, connect,
+    counters::{WorkCounted, COUNTERS},
+    selector::SELECTOR,
+    Benched, Client, Exclusive, Fork, Sqlite, Transaction,
+};
 
 use crate::tri;
 
 /// Runs the each_identity benchmark on a Neo4j backend.
-fn bench<B: Benched + Doublets<usize>>(
+fn bench<B: Benched + Doublets<usize> + WorkCounted>(
     group: &mut BenchmarkGroup<WallTime>,
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
     let handler = |_| Flow::Continue;
     let any = LinksConstants::new().any;
+    let before = benched.work_counts();
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
+        bench!(|fork| as B, "Each_Identity" {
             use linksneo4j::BACKGROUND_LINKS;
-            for index in 1..=BACKGROUND_LINKS {
+            for index in 1..=*BACKGROUND_LINKS {
                 elapsed! {fork.each_by([index, any, any], handler)};
             }
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
     });
+    let after = benched.work_counts();
+    COUNTERS.record(id, "Each_Identity", after - before);
 }
 
 /// Creates benchmark for Neo4j backends on ID lookup.
 pub fn each_identity(c: &mut Criterion) {
+    if !SELECTOR.operation("each_identity") {
+        return;
+    }
     let mut group = c.benchmark_group("Each_Identity");
+    group.throughput(Throughput::Elements(*linksneo4j::BACKGROUND_LINKS as u64));
 
     tri! {
         bench(&mut group, "Neo4j_NonTransaction", Exclusive::<Client<usize>>::setup(()).unwrap());
@@ -54,6 +69,20 @@ pub fn each_identity(c: &mut Criterion) {
             Exclusive::<Transaction<'_, usize>>::setup(&client).unwrap(),
         );
     }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
 
     group.finish();
 }
@@ -15,10 +15,10 @@
 
 use std::time::{Duration, Instant};
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
 use doublets::data::{Flow, LinksConstants};
 use doublets::Doublets;
-use linksneo4j::{bench, connect, Benched, Client, Exclusive, Fork, Transaction};
+use linksneo4j::{bench, connect, selector::SELECTOR, Benched, Client, Exclusive, Fork, Sqlite, Transaction};
 
 use crate::tri;
 
@@ -28,21 +28,28 @@ fn bench<B: Benched + Doublets<usize>>(
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
     let handler = |_| Flow::Continue;
     let any = LinksConstants::new().any;
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
+        bench!(|fork| as B, "Each_Incoming" {
             use linksneo4j::BACKGROUND_LINKS;
-            for index in 1..=BACKGROUND_LINKS {
+            for index in 1..=*BACKGROUND_LINKS {
                 elapsed! {fork.each_by([any, any, index], handler)};
             }
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
     });
 }
 
 /// Creates benchmark for Neo4j backends on target index lookup.
 pub fn each_incoming(c: &mut Criterion) {
+    if !SELECTOR.operation("each_incoming") {
+        return;
+    }
     let mut group = c.benchmark_group("Each_Incoming");
+    group.throughput(Throughput::Elements(*linksneo4j::BACKGROUND_LINKS as u64));
 
     tri! {
         bench(&mut group, "Neo4j_NonTransaction", Exclusive::<Client<usize>>::setup(()).unwrap());
@@ -55,6 +62,20 @@ pub fn each_incoming(c: &mut Criterion) {
             Exclusive::<Transaction<'_, usize>>::setup(&client).unwrap(),
         );
     }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
 
     group.finish();
 }
@@ -22,11 +22,13 @@
 //! - `Neo4j_NonTransaction` - Direct HTTP API calls
 //! - `Neo4j_Transaction` - Transaction wrapper (same underlying implementation)
 
+mod bulk;
 mod create;
 mod delete;
 pub mod each;
 mod update;
 
+pub use bulk::{bulk_create, bulk_delete};
 pub use create::create_links;
 pub use delete::delete_links;
 pub use each::*;
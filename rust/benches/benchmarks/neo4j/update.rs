@@ -8,6 +8,10 @@
 //! ```cypher
 //! MATCH (l:Link {id: $id}) SET l.source = $source, l.target = $target
 //! ```
+//! with both updates per id drawing their `source`/`target` from
+//! [`linksneo4j::workload::generate`] rather than the degenerate `(0, 0)`
+//! then `(id, id)` self-loop, so the property re-index above is exercised
+//! against realistic, non-uniform degree.
 //!
 //! - Makes HTTP request to `/db/neo4j/tx/commit`
 //! - Neo4j finds node by indexed id property
@@ -16,33 +20,52 @@
 
 use std::time::{Duration, Instant};
 
-use criterion::{measurement::WallTime, BenchmarkGroup, Criterion};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
 use doublets::Doublets;
-use linksneo4j::{bench, connect, Benched, Client, Exclusive, Fork, Transaction, LINK_COUNT};
+use linksneo4j::{
+    bench, connect,
+    counters::{WorkCounted, COUNTERS},
+    selector::SELECTOR,
+    workload, Batched, Benched, Client, Exclusive, Fork, Sqlite, Transaction, LINK_COUNT,
+    NEO4J_BATCH_SIZE,
+};
 
 use crate::tri;
 
 /// Runs the update benchmark on a Neo4j backend.
-fn bench<B: Benched + Doublets<usize>>(
+fn bench<B: Benched + Doublets<usize> + WorkCounted>(
     group: &mut BenchmarkGroup<WallTime>,
     id: &str,
     mut benched: B,
 ) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let before = benched.work_counts();
     group.bench_function(id, |bencher| {
-        bench!(|fork| as B {
+        bench!(|fork| as B, "Update" {
             use linksneo4j::BACKGROUND_LINKS;
-            let start_id = if BACKGROUND_LINKS > *LINK_COUNT { BACKGROUND_LINKS - *LINK_COUNT + 1 } else { 1 };
-            for id in start_id..=BACKGROUND_LINKS {
-                let _ = elapsed! {fork.update(id, 0, 0)?};
-                let _ = elapsed! {fork.update(id, id, id)?};
+            let start_id = if *BACKGROUND_LINKS > *LINK_COUNT { *BACKGROUND_LINKS - *LINK_COUNT + 1 } else { 1 };
+            let generated = workload::generate(2 * (*BACKGROUND_LINKS - start_id + 1));
+            for (i, id) in (start_id..=*BACKGROUND_LINKS).enumerate() {
+                let (first_source, first_target) = generated.edges[2 * i];
+                let (second_source, second_target) = generated.edges[2 * i + 1];
+                let _ = elapsed! {fork.update(id, first_source, first_target)?};
+                let _ = elapsed! {fork.update(id, second_source, second_target)?};
             }
-        })(bencher, &mut benched);
+        })(bencher, &mut benched, id);
     });
+    let after = benched.work_counts();
+    COUNTERS.record(id, "Update", after - before);
 }
 
 /// Creates benchmark for Neo4j backends on link updates.
 pub fn update_links(c: &mut Criterion) {
+    if !SELECTOR.operation("update") {
+        return;
+    }
     let mut group = c.benchmark_group("Update");
+    group.throughput(Throughput::Elements(2 * *LINK_COUNT as u64));
 
     tri! {
         bench(&mut group, "Neo4j_NonTransaction", Exclusive::<Client<usize>>::setup(()).unwrap());
@@ -55,6 +78,28 @@ pub fn update_links(c: &mut Criterion) {
             Exclusive::<Transaction<'_, usize>>::setup(&client).unwrap(),
         );
     }
+    tri! {
+        let client = connect().unwrap();
+        bench(
+            &mut group,
+            "Neo4j_Batched",
+            Exclusive::<Batched<'_, usize>>::setup((&client, *NEO4J_BATCH_SIZE)).unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_Volatile",
+            Exclusive::<Sqlite<usize>>::setup(":memory:").unwrap(),
+        );
+    }
+    tri! {
+        bench(
+            &mut group,
+            "Sqlite_NonVolatile",
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db").unwrap(),
+        );
+    }
 
     group.finish();
 }
@@ -0,0 +1,115 @@
+//! Doublets side of the scaling-curve benchmark. Sweeps
+//! [`linksneo4j::scaling::scaling_sizes`] against each Doublets/SQLite
+//! backend, fitting fixed overhead vs. marginal per-link cost for
+//! `create_point`.
+
+use std::{
+    alloc::Global,
+    time::Instant,
+};
+
+use criterion::Criterion;
+use doublets::{
+    mem::{Alloc, FileMapped},
+    parts::LinkPart,
+    split::{self, DataPart, IndexPart},
+    unit, Doublets,
+};
+use linksneo4j::{
+    scaling::{median, scaling_sizes, LinearFit},
+    selector::SELECTOR,
+    Benched, Exclusive, Fork, Sqlite,
+};
+
+use crate::tri;
+
+/// Number of `create_point` calls timed at each swept size.
+const SAMPLE_OPS: usize = 100;
+/// Number of repeated measurements taken at each swept size, summarized by
+/// their median to smooth out one-off scheduling noise.
+const REPEATS: usize = 5;
+
+/// Times `SAMPLE_OPS` creates on a freshly-populated `size`-link backend,
+/// repeated `REPEATS` times, returning the median duration in nanoseconds.
+fn median_create_time<B: Doublets<usize>>(fork: &mut Fork<B>, size: usize) -> f64 {
+    for _ in 0..size {
+        let _ = fork.create_point();
+    }
+
+    let mut samples = (0..REPEATS)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..SAMPLE_OPS {
+                let _ = fork.create_point();
+            }
+            start.elapsed().as_nanos() as f64
+        })
+        .collect::<Vec<_>>();
+
+    median(&mut samples)
+}
+
+fn scaling_curve_for<B: Benched + Doublets<usize>>(id: &str, setup: impl Fn() -> linksneo4j::Result<B>) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let samples: Vec<(f64, f64)> = scaling_sizes()
+        .into_iter()
+        .filter(|&size| SELECTOR.size(size))
+        .filter_map(|size| {
+            let mut benched = setup().ok()?;
+            let mut fork = Benched::fork(&mut benched);
+            let time = median_create_time(&mut fork, size);
+            Some((size as f64, time))
+        })
+        .collect();
+
+    match LinearFit::fit(&samples) {
+        Ok(fit) => println!(
+            "Scaling[{id}]: intercept={:.1}ns slope={:.3}ns/link r²={:.4} (n={})",
+            fit.intercept,
+            fit.slope,
+            fit.r_squared,
+            samples.len()
+        ),
+        Err(err) => println!("Scaling[{id}]: {err} (n={})", samples.len()),
+    }
+}
+
+/// Runs the scaling-curve sweep against every Doublets/SQLite backend.
+pub fn scaling_curve(_c: &mut Criterion) {
+    if !SELECTOR.operation("scaling_curve") {
+        return;
+    }
+    tri! {
+        scaling_curve_for("Doublets_United_Volatile", || {
+            unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(())
+        });
+    }
+    tri! {
+        scaling_curve_for("Doublets_United_NonVolatile", || {
+            unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links")
+        });
+    }
+    tri! {
+        scaling_curve_for("Doublets_Split_Volatile", || {
+            split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(())
+        });
+    }
+    tri! {
+        scaling_curve_for("Doublets_Split_NonVolatile", || {
+            split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup((
+                "split_index.links",
+                "split_data.links",
+            ))
+        });
+    }
+    tri! {
+        scaling_curve_for("Sqlite_Volatile", || Exclusive::<Sqlite<usize>>::setup(":memory:"));
+    }
+    tri! {
+        scaling_curve_for("Sqlite_NonVolatile", || {
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db")
+        });
+    }
+}
@@ -0,0 +1,22 @@
+//! # Scaling-Curve Benchmark
+//!
+//! Every other benchmark in this crate times a fixed [`linksneo4j::BACKGROUND_LINKS`]
+//! database, which hides how each backend scales and lumps a backend's fixed
+//! per-request overhead in with its marginal per-link cost. This module
+//! re-runs the `create_point` operation across a swept range of database
+//! sizes (see [`linksneo4j::scaling::scaling_sizes`]) and fits a linear model
+//! `time = intercept + slope * size` to the medians, printing the two terms
+//! plus the fit's R² so a Neo4j network round-trip doesn't get folded into
+//! its per-link cost, and a poor fit (non-linear scaling) is visible rather
+//! than silently trusted.
+//!
+//! ## Module Structure
+//!
+//! - **[`neo4j`]** - Swept against the Neo4j backends.
+//! - **[`doublets`]** - Swept against the Doublets and SQLite backends.
+
+pub mod doublets;
+pub mod neo4j;
+
+pub use self::doublets::scaling_curve as doublets_scaling_curve;
+pub use neo4j::scaling_curve as neo4j_scaling_curve;
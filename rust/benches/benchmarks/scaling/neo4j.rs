@@ -0,0 +1,73 @@
+//! Neo4j side of the scaling-curve benchmark. Covers only
+//! `Neo4j_NonTransaction`, mirroring the concurrent-throughput benchmark's
+//! choice to skip `Neo4j_Transaction` here -- `Transaction<'_, T>` borrows a
+//! `Client` that would need to outlive each swept size's fresh backend,
+//! which the `Benched::setup` closure can't express.
+
+use std::time::Instant;
+
+use criterion::Criterion;
+use doublets::Doublets;
+use linksneo4j::{
+    scaling::{median, scaling_sizes, LinearFit},
+    selector::SELECTOR,
+    Benched, Client, Exclusive, Fork,
+};
+
+use crate::tri;
+
+/// Number of `create_point` calls timed at each swept size.
+const SAMPLE_OPS: usize = 100;
+/// Number of repeated measurements taken at each swept size, summarized by
+/// their median to smooth out one-off network jitter.
+const REPEATS: usize = 5;
+
+/// Times `SAMPLE_OPS` creates on a freshly-populated `size`-link backend,
+/// repeated `REPEATS` times, returning the median duration in nanoseconds.
+fn median_create_time<B: Doublets<usize>>(fork: &mut Fork<B>, size: usize) -> f64 {
+    for _ in 0..size {
+        let _ = fork.create_point();
+    }
+
+    let mut samples = (0..REPEATS)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..SAMPLE_OPS {
+                let _ = fork.create_point();
+            }
+            start.elapsed().as_nanos() as f64
+        })
+        .collect::<Vec<_>>();
+
+    median(&mut samples)
+}
+
+/// Runs the scaling-curve sweep against `Neo4j_NonTransaction`.
+pub fn scaling_curve(_c: &mut Criterion) {
+    if !SELECTOR.operation("scaling_curve") || !SELECTOR.backend("Neo4j_NonTransaction") {
+        return;
+    }
+    tri! {
+        let samples: Vec<(f64, f64)> = scaling_sizes()
+            .into_iter()
+            .filter(|&size| SELECTOR.size(size))
+            .filter_map(|size| {
+                let mut benched = Exclusive::<Client<usize>>::setup(()).ok()?;
+                let mut fork = Benched::fork(&mut benched);
+                let time = median_create_time(&mut fork, size);
+                Some((size as f64, time))
+            })
+            .collect();
+
+        match LinearFit::fit(&samples) {
+            Ok(fit) => println!(
+                "Scaling[Neo4j_NonTransaction]: intercept={:.1}ns slope={:.3}ns/link r²={:.4} (n={})",
+                fit.intercept,
+                fit.slope,
+                fit.r_squared,
+                samples.len()
+            ),
+            Err(err) => println!("Scaling[Neo4j_NonTransaction]: {err} (n={})", samples.len()),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Doublets side of the snapshot export/import benchmark. Populates
+//! `*LINK_COUNT` point links with workload-generated source/target edges
+//! (same generator as [`doublets::create`](crate::benchmarks::doublets::create)),
+//! reads the source/target columns back via `each()` in id order -- link
+//! ids themselves are known directly, since point links are created
+//! sequentially starting at 1 in a fresh fork, so no id needs reading back
+//! -- then times [`Snapshot::encode`]/[`Snapshot::decode`] over the
+//! id/source/target columns.
+
+use std::{alloc::Global, time::Instant};
+
+use criterion::Criterion;
+use doublets::{
+    data::Flow,
+    mem::{Alloc, FileMapped},
+    parts::LinkPart,
+    split::{self, DataPart, IndexPart},
+    unit, Doublets,
+};
+use linksneo4j::{
+    selector::SELECTOR, snapshot::Snapshot, workload, Benched, Exclusive, Fork, RocksDb, Sqlite,
+    LINK_COUNT,
+};
+
+use crate::tri;
+
+/// Creates `link_count` point links, then updates each to a
+/// workload-generated `(source, target)` pair.
+fn populate<B: Doublets<usize>>(fork: &mut Fork<B>, link_count: usize) -> linksneo4j::Result<()> {
+    let generated = workload::generate(link_count);
+    for _ in 0..link_count {
+        let _ = fork.create_point()?;
+    }
+    for (i, &(source, target)) in generated.edges.iter().enumerate() {
+        fork.update(i + 1, source, target)?;
+    }
+    Ok(())
+}
+
+/// Reads the source/target columns back via `each()`. Ids aren't read
+/// back: `populate` created them sequentially, so they're just `1..=link_count`.
+fn collect_columns<B: Doublets<usize>>(fork: &mut Fork<B>, link_count: usize) -> (Vec<i64>, Vec<i64>) {
+    let mut sources = Vec::with_capacity(link_count);
+    let mut targets = Vec::with_capacity(link_count);
+    fork.each(|link| {
+        sources.push(link.source as i64);
+        targets.push(link.target as i64);
+        Flow::Continue
+    });
+    (sources, targets)
+}
+
+fn export_for<B: Benched + Doublets<usize>>(id: &str, setup: impl Fn() -> linksneo4j::Result<B>) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let link_count = *LINK_COUNT;
+    let Ok(mut benched) = setup() else { return };
+    let mut fork = Benched::fork(&mut benched);
+    if populate(&mut fork, link_count).is_err() {
+        return;
+    }
+    let (sources, targets) = collect_columns(&mut fork, link_count);
+    let ids: Vec<i64> = (1..=link_count as i64).collect();
+
+    let start = Instant::now();
+    let snapshot = Snapshot::encode(&ids, &sources, &targets);
+    let elapsed = start.elapsed();
+
+    let bytes = snapshot.byte_len();
+    let bytes_per_link = bytes as f64 / link_count.max(1) as f64;
+    let mbps = bytes as f64 / 1_000_000.0 / elapsed.as_secs_f64().max(f64::EPSILON);
+    println!(
+        "Snapshot_Export[{id}]: {bytes} bytes for {link_count} links ({bytes_per_link:.2} bytes/link) in {elapsed:?} ({mbps:.2} MB/s)",
+    );
+}
+
+fn import_for<B: Benched + Doublets<usize>>(id: &str, setup: impl Fn() -> linksneo4j::Result<B>) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let link_count = *LINK_COUNT;
+    let Ok(mut benched) = setup() else { return };
+    let mut fork = Benched::fork(&mut benched);
+    if populate(&mut fork, link_count).is_err() {
+        return;
+    }
+    let (sources, targets) = collect_columns(&mut fork, link_count);
+    let ids: Vec<i64> = (1..=link_count as i64).collect();
+    let snapshot = Snapshot::encode(&ids, &sources, &targets);
+    let bytes = snapshot.byte_len();
+
+    let start = Instant::now();
+    let _ = snapshot.decode();
+    let elapsed = start.elapsed();
+
+    let mbps = bytes as f64 / 1_000_000.0 / elapsed.as_secs_f64().max(f64::EPSILON);
+    println!(
+        "Snapshot_Import[{id}]: {bytes} bytes for {link_count} links in {elapsed:?} ({mbps:.2} MB/s)",
+    );
+}
+
+/// Runs the snapshot-export benchmark against every Doublets/SQLite/RocksDB
+/// backend.
+pub fn snapshot_export(_c: &mut Criterion) {
+    if !SELECTOR.operation("snapshot_export") {
+        return;
+    }
+
+    tri! {
+        export_for("Doublets_United_Volatile", || {
+            unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(())
+        });
+    }
+    tri! {
+        export_for("Doublets_United_NonVolatile", || {
+            unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links")
+        });
+    }
+    tri! {
+        export_for("Doublets_Split_Volatile", || {
+            split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(())
+        });
+    }
+    tri! {
+        export_for("Doublets_Split_NonVolatile", || {
+            split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup((
+                "split_index.links",
+                "split_data.links",
+            ))
+        });
+    }
+    tri! {
+        export_for("Sqlite_Volatile", || Exclusive::<Sqlite<usize>>::setup(":memory:"));
+    }
+    tri! {
+        export_for("Sqlite_NonVolatile", || {
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db")
+        });
+    }
+    tri! {
+        export_for("Doublets_RocksDB", || {
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench")
+        });
+    }
+}
+
+/// Runs the snapshot-import benchmark against every Doublets/SQLite/RocksDB
+/// backend.
+pub fn snapshot_import(_c: &mut Criterion) {
+    if !SELECTOR.operation("snapshot_import") {
+        return;
+    }
+
+    tri! {
+        import_for("Doublets_United_Volatile", || {
+            unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(())
+        });
+    }
+    tri! {
+        import_for("Doublets_United_NonVolatile", || {
+            unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links")
+        });
+    }
+    tri! {
+        import_for("Doublets_Split_Volatile", || {
+            split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(())
+        });
+    }
+    tri! {
+        import_for("Doublets_Split_NonVolatile", || {
+            split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup((
+                "split_index.links",
+                "split_data.links",
+            ))
+        });
+    }
+    tri! {
+        import_for("Sqlite_Volatile", || Exclusive::<Sqlite<usize>>::setup(":memory:"));
+    }
+    tri! {
+        import_for("Sqlite_NonVolatile", || {
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db")
+        });
+    }
+    tri! {
+        import_for("Doublets_RocksDB", || {
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench")
+        });
+    }
+}
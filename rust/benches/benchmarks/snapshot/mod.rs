@@ -0,0 +1,20 @@
+//! # Snapshot Export/Import Benchmark
+//!
+//! Most benchmarks in this crate measure per-operation latency. This one
+//! measures storage-level serialization cost instead: it populates a
+//! backend with `*LINK_COUNT` links, reads back the id/source/target
+//! columns in id order, and times [`linksneo4j::snapshot::Snapshot::encode`]
+//! (`snapshot_export`) / `decode` (`snapshot_import`) over them, reporting
+//! bytes-per-link and MB/s. The codec itself is shared by every backend, so
+//! what's actually compared is how cheaply each backend can hand back its
+//! link set in id order for the encoder to consume.
+
+pub mod doublets;
+pub mod neo4j;
+
+pub use self::doublets::{
+    snapshot_export as doublets_snapshot_export, snapshot_import as doublets_snapshot_import,
+};
+pub use neo4j::{
+    snapshot_export as neo4j_snapshot_export, snapshot_import as neo4j_snapshot_import,
+};
@@ -0,0 +1,130 @@
+//! Neo4j side of the snapshot export/import benchmark. Populates
+//! `*LINK_COUNT` links via a single `UNWIND`-batched `CREATE` (so
+//! population doesn't dominate the measured encode/decode time), streams
+//! them back via `MATCH (l:Link) RETURN l.id, l.source, l.target ORDER BY
+//! l.id`, then times [`Snapshot::encode`]/[`Snapshot::decode`] over the
+//! returned id/source/target columns -- the same codec
+//! [`doublets`](super::doublets) uses, so the two backends' raw
+//! serialization cost is comparable independent of their very different
+//! per-row read paths.
+
+use std::time::Instant;
+
+use criterion::Criterion;
+use linksneo4j::{selector::SELECTOR, snapshot::Snapshot, workload, Client, LINK_COUNT};
+use serde_json::json;
+
+use crate::tri;
+
+/// Replaces the database with `link_count` workload-generated links, then
+/// streams the id/source/target columns back in id order.
+fn populate_and_collect(
+    client: &Client<usize>,
+    link_count: usize,
+) -> linksneo4j::Result<(Vec<i64>, Vec<i64>, Vec<i64>)> {
+    let _ = client.execute_cypher("MATCH (l:Link) DETACH DELETE l", None);
+
+    let generated = workload::generate(link_count);
+    let rows: Vec<_> = generated
+        .edges
+        .iter()
+        .enumerate()
+        .map(|(i, &(source, target))| json!({ "id": i + 1, "source": source, "target": target }))
+        .collect();
+    client.execute_cypher(
+        "UNWIND $rows AS r CREATE (l:Link {id: r.id, source: r.source, target: r.target})",
+        Some(json!({ "rows": rows })),
+    )?;
+
+    let response = client.execute_cypher(
+        "MATCH (l:Link) RETURN l.id as id, l.source as source, l.target as target ORDER BY l.id",
+        None,
+    )?;
+
+    let mut ids = Vec::with_capacity(link_count);
+    let mut sources = Vec::with_capacity(link_count);
+    let mut targets = Vec::with_capacity(link_count);
+    if let Some(result) = response.results.first() {
+        for row in &result.data {
+            if row.row.len() >= 3 {
+                ids.push(row.row[0].as_i64().unwrap_or(0));
+                sources.push(row.row[1].as_i64().unwrap_or(0));
+                targets.push(row.row[2].as_i64().unwrap_or(0));
+            }
+        }
+    }
+
+    Ok((ids, sources, targets))
+}
+
+fn export_for(id: &str) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let link_count = *LINK_COUNT;
+    let Ok(client) = linksneo4j::connect::<usize>() else {
+        return;
+    };
+    let Ok((ids, sources, targets)) = populate_and_collect(&client, link_count) else {
+        return;
+    };
+
+    let start = Instant::now();
+    let snapshot = Snapshot::encode(&ids, &sources, &targets);
+    let elapsed = start.elapsed();
+
+    let bytes = snapshot.byte_len();
+    let bytes_per_link = bytes as f64 / link_count.max(1) as f64;
+    let mbps = bytes as f64 / 1_000_000.0 / elapsed.as_secs_f64().max(f64::EPSILON);
+    println!(
+        "Snapshot_Export[{id}]: {bytes} bytes for {link_count} links ({bytes_per_link:.2} bytes/link) in {elapsed:?} ({mbps:.2} MB/s)",
+    );
+}
+
+fn import_for(id: &str) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+    let link_count = *LINK_COUNT;
+    let Ok(client) = linksneo4j::connect::<usize>() else {
+        return;
+    };
+    let Ok((ids, sources, targets)) = populate_and_collect(&client, link_count) else {
+        return;
+    };
+    let snapshot = Snapshot::encode(&ids, &sources, &targets);
+    let bytes = snapshot.byte_len();
+
+    let start = Instant::now();
+    let _ = snapshot.decode();
+    let elapsed = start.elapsed();
+
+    let mbps = bytes as f64 / 1_000_000.0 / elapsed.as_secs_f64().max(f64::EPSILON);
+    println!(
+        "Snapshot_Import[{id}]: {bytes} bytes for {link_count} links in {elapsed:?} ({mbps:.2} MB/s)",
+    );
+}
+
+/// Runs the snapshot-export benchmark against the Neo4j non-transactional
+/// backend (the only Neo4j wrapper with a public `execute_cypher`).
+pub fn snapshot_export(_c: &mut Criterion) {
+    if !SELECTOR.operation("snapshot_export") {
+        return;
+    }
+
+    tri! {
+        export_for("Neo4j_NonTransaction");
+    }
+}
+
+/// Runs the snapshot-import benchmark against the Neo4j non-transactional
+/// backend (the only Neo4j wrapper with a public `execute_cypher`).
+pub fn snapshot_import(_c: &mut Criterion) {
+    if !SELECTOR.operation("snapshot_import") {
+        return;
+    }
+
+    tri! {
+        import_for("Neo4j_NonTransaction");
+    }
+}
@@ -0,0 +1,139 @@
+//! Doublets side of the graph-traversal benchmark. Materializes the seeded
+//! synthetic graph (see [`linksneo4j::topology`]) as one link per edge --
+//! `create_point()` then `update(id, source, target)`, same as every other
+//! Doublets benchmark builds its background data -- then runs a plain BFS:
+//! a `VecDeque` frontier and a `HashSet` of visited node ids, popping a
+//! node `u` and calling `fork.each_by([any, u, any], ..)` to find its
+//! outgoing edges, same index lookup `each_outgoing` measures, just
+//! chained across hops instead of run once per background link.
+
+use std::{
+    alloc::Global,
+    collections::{HashSet, VecDeque},
+    time::Instant,
+};
+
+use criterion::Criterion;
+use doublets::{
+    data::{Flow, LinksConstants},
+    mem::{Alloc, FileMapped},
+    parts::LinkPart,
+    split::{self, DataPart, IndexPart},
+    unit, Doublets,
+};
+use linksneo4j::{
+    selector::SELECTOR,
+    topology::{self, Topology},
+    Benched, Exclusive, Fork, RocksDb, Sqlite,
+};
+
+use crate::tri;
+
+/// Creates one link per [`Topology`] edge: a point link for its id, then
+/// `update`d to carry the edge's `source`/`target` node ids.
+fn build_topology<B: Doublets<usize>>(fork: &mut Fork<B>, topology: &Topology) -> linksneo4j::Result<()> {
+    for _ in &topology.edges {
+        let _ = fork.create_point()?;
+    }
+    for (id, edge) in (1..).zip(&topology.edges) {
+        fork.update(id, edge.source, edge.target)?;
+    }
+    Ok(())
+}
+
+/// BFS from `start`, following outgoing edges (`each_by([any, u, any])`)
+/// up to `max_depth` hops, returning the number of distinct nodes visited
+/// (including `start`).
+fn bfs_reachable<B: Doublets<usize>>(fork: &mut Fork<B>, start: usize, max_depth: usize) -> usize {
+    let any = LinksConstants::new().any;
+    let mut visited = HashSet::new();
+    visited.insert(start);
+    let mut frontier = VecDeque::from([(start, 0usize)]);
+
+    while let Some((node, depth)) = frontier.pop_front() {
+        if depth >= max_depth {
+            continue;
+        }
+        let mut targets = Vec::new();
+        fork.each_by([any, node, any], |link| {
+            targets.push(link.target);
+            Flow::Continue
+        });
+        for target in targets {
+            if visited.insert(target) {
+                frontier.push_back((target, depth + 1));
+            }
+        }
+    }
+
+    visited.len()
+}
+
+/// Builds the synthetic graph in a fresh `B`, times one BFS reachability
+/// pass over it, and prints wall time + visited-node count for `id`.
+fn traversal_for<B: Benched + Doublets<usize>>(id: &str, setup: impl Fn() -> linksneo4j::Result<B>) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+
+    let topology = topology::generate();
+    let Ok(mut benched) = setup() else { return };
+    let mut fork = Benched::fork(&mut benched);
+    if build_topology(&mut fork, &topology).is_err() {
+        return;
+    }
+
+    let start = Instant::now();
+    let visited = bfs_reachable(&mut fork, topology.start, topology.max_depth);
+    let elapsed = start.elapsed();
+
+    println!(
+        "Traversal[{id}]: visited {visited}/{} nodes in {elapsed:?} (max_depth={})",
+        topology.node_count, topology.max_depth,
+    );
+}
+
+/// Runs the graph-traversal benchmark against every Doublets/SQLite/RocksDB
+/// backend.
+pub fn graph_traversal(_c: &mut Criterion) {
+    if !SELECTOR.operation("graph_traversal") {
+        return;
+    }
+
+    tri! {
+        traversal_for("Doublets_United_Volatile", || {
+            unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(())
+        });
+    }
+    tri! {
+        traversal_for("Doublets_United_NonVolatile", || {
+            unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links")
+        });
+    }
+    tri! {
+        traversal_for("Doublets_Split_Volatile", || {
+            split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(())
+        });
+    }
+    tri! {
+        traversal_for("Doublets_Split_NonVolatile", || {
+            split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup((
+                "split_index.links",
+                "split_data.links",
+            ))
+        });
+    }
+    tri! {
+        traversal_for("Sqlite_Volatile", || Exclusive::<Sqlite<usize>>::setup(":memory:"));
+    }
+    tri! {
+        traversal_for("Sqlite_NonVolatile", || {
+            Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db")
+        });
+    }
+    tri! {
+        traversal_for("Doublets_RocksDB", || {
+            Exclusive::<RocksDb<usize>>::setup("rocksdb_bench")
+        });
+    }
+}
@@ -0,0 +1,24 @@
+//! # Graph Traversal Benchmark
+//!
+//! Every `each_*` benchmark measures a single index lookup, which is not
+//! what graph databases are built for -- their value shows up over
+//! multi-hop queries. This module builds the same seeded synthetic graph
+//! (see [`linksneo4j::topology`]) in each backend and computes the full
+//! set of links reachable from a fixed start node by repeatedly following
+//! outgoing edges, up to a configurable max depth, reporting both wall
+//! time and the number of nodes visited.
+//!
+//! ## Module Structure
+//!
+//! - **[`doublets`]** - BFS driven from this crate, one `each_by([any, u,
+//!   any], ..)` index lookup per frontier node -- the same index-tree walk
+//!   `each_outgoing` measures, just chained across hops.
+//! - **[`neo4j`]** - A single bounded variable-length-path Cypher query
+//!   (`-[:POINTS_TO*1..depth]->`), since that is the one-query form this
+//!   workload is native to for Neo4j.
+
+pub mod doublets;
+pub mod neo4j;
+
+pub use self::doublets::graph_traversal as doublets_graph_traversal;
+pub use neo4j::graph_traversal as neo4j_graph_traversal;
@@ -0,0 +1,104 @@
+//! Neo4j side of the graph-traversal benchmark. Materializes the seeded
+//! synthetic graph (see [`linksneo4j::topology`]) as `:Link` nodes joined
+//! by `:POINTS_TO` relationships -- the other benchmarks never need real
+//! relationships since they only ever filter on the `source`/`target`
+//! properties, but a multi-hop traversal is exactly the pattern Neo4j's
+//! variable-length paths are for, so this is the one benchmark in the
+//! crate that creates them. Reachability is then a single bounded
+//! variable-length-path query instead of the per-hop index lookups the
+//! Doublets side has to chain.
+//!
+//! Only `Neo4j_NonTransaction` is benched here, same as the
+//! concurrent-throughput benchmark: `Transaction` doesn't expose a public
+//! `execute_cypher`, only the trait methods needed for the CRUD/`each_by`
+//! benchmarks.
+
+use std::time::Instant;
+
+use criterion::Criterion;
+use linksneo4j::{
+    selector::SELECTOR,
+    topology::{self, Topology},
+    Client,
+};
+use serde_json::json;
+
+use crate::tri;
+
+/// Creates one `:Link` node per graph node, then a `:POINTS_TO`
+/// relationship per [`Topology`] edge, both batched via `UNWIND` the same
+/// way [`linksneo4j`]'s bulk-write benchmark batches plain link creation.
+fn build_topology(client: &Client<usize>, topology: &Topology) -> linksneo4j::Result<()> {
+    let node_ids: Vec<usize> = (1..=topology.node_count).collect();
+    client.execute_cypher(
+        "UNWIND $ids AS id CREATE (l:Link {id: id, source: 0, target: 0})",
+        Some(json!({ "ids": node_ids })),
+    )?;
+
+    let rows: Vec<_> = topology
+        .edges
+        .iter()
+        .map(|edge| json!({ "source": edge.source, "target": edge.target }))
+        .collect();
+    client.execute_cypher(
+        "UNWIND $rows AS r MATCH (s:Link {id: r.source}), (t:Link {id: r.target}) CREATE (s)-[:POINTS_TO]->(t)",
+        Some(json!({ "rows": rows })),
+    )?;
+
+    Ok(())
+}
+
+/// Runs the bounded variable-length-path query and returns the number of
+/// distinct nodes it found reachable from `topology.start`.
+fn bfs_reachable(client: &Client<usize>, topology: &Topology) -> linksneo4j::Result<usize> {
+    let query = format!(
+        "MATCH (s:Link {{id: $start}})-[:POINTS_TO*1..{}]->(n:Link) RETURN DISTINCT n.id",
+        topology.max_depth,
+    );
+    let response = client.execute_cypher(&query, Some(json!({ "start": topology.start })))?;
+    let visited = response
+        .results
+        .first()
+        .map(|result| result.data.len())
+        .unwrap_or(0);
+    // The start node itself never matches a `*1..depth` path, so count it too.
+    Ok(visited + 1)
+}
+
+/// Builds the synthetic graph in a fresh Neo4j database, times one
+/// reachability query over it, and prints wall time + visited-node count.
+fn traversal_for(id: &str) {
+    if !SELECTOR.backend(id) {
+        return;
+    }
+
+    let topology = topology::generate();
+    let Ok(client) = linksneo4j::connect::<usize>() else {
+        return;
+    };
+    if build_topology(&client, &topology).is_err() {
+        return;
+    }
+
+    let start = Instant::now();
+    let Ok(visited) = bfs_reachable(&client, &topology) else {
+        return;
+    };
+    let elapsed = start.elapsed();
+
+    println!(
+        "Traversal[{id}]: visited {visited}/{} nodes in {elapsed:?} (max_depth={})",
+        topology.node_count, topology.max_depth,
+    );
+}
+
+/// Runs the graph-traversal benchmark against the Neo4j backend.
+pub fn graph_traversal(_c: &mut Criterion) {
+    if !SELECTOR.operation("graph_traversal") {
+        return;
+    }
+
+    tri! {
+        traversal_for("Neo4j_NonTransaction");
+    }
+}
@@ -0,0 +1,333 @@
+// Batched wraps a Client and defers create/update/delete operations
+// instead of issuing one HTTP round-trip per link like Client/Transaction
+// do. Each kind of operation accumulates into its own buffer; once a
+// buffer reaches `batch_size` it's flushed as a single `UNWIND`-batched
+// Cypher statement. `drop_table` flushes whatever is still pending before
+// wiping the database, so nothing is lost between benchmark iterations
+// and `Benched::unfork` (which calls `drop_table`) always leaves the
+// buffers empty.
+
+use doublets::{
+    data::{Error, Flow, LinkType, LinksConstants, ReadHandler, WriteHandler},
+    Doublets, Link, Links,
+};
+use serde_json::json;
+
+use crate::{Client, Exclusive, Result, Sql};
+
+pub struct Batched<'a, T: LinkType> {
+    client: &'a Client<T>,
+    batch_size: usize,
+    pending_creates: Vec<i64>,
+    pending_updates: Vec<(i64, i64, i64)>,
+    pending_deletes: Vec<i64>,
+}
+
+impl<'a, T: LinkType> Batched<'a, T> {
+    pub fn new(client: &'a Client<T>, batch_size: usize) -> Self {
+        Self {
+            client,
+            batch_size: batch_size.max(1),
+            pending_creates: Vec::new(),
+            pending_updates: Vec::new(),
+            pending_deletes: Vec::new(),
+        }
+    }
+
+    /// The client this batch buffers operations for, e.g. to read its
+    /// HTTP round-trip/byte counters, or to open another batch on the same
+    /// connection (see `Benched::fork_concurrent`).
+    pub fn client(&self) -> &'a Client<T> {
+        self.client
+    }
+
+    /// The batch size this instance flushes at, e.g. to open another batch
+    /// with the same setting (see `Benched::fork_concurrent`).
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Flushes every buffered create/update/delete as one `UNWIND` Cypher
+    /// statement per kind. A no-op for any buffer that's currently empty.
+    pub fn flush(&mut self) -> Result<()> {
+        if !self.pending_creates.is_empty() {
+            let rows: Vec<_> = self
+                .pending_creates
+                .drain(..)
+                .map(|id| json!({ "id": id }))
+                .collect();
+            self.client.execute_cypher(
+                "UNWIND $rows AS r CREATE (l:Link {id: r.id, source: 0, target: 0})",
+                Some(json!({ "rows": rows })),
+            )?;
+        }
+        if !self.pending_updates.is_empty() {
+            let rows: Vec<_> = self
+                .pending_updates
+                .drain(..)
+                .map(|(id, source, target)| {
+                    json!({ "id": id, "source": source, "target": target })
+                })
+                .collect();
+            self.client.execute_cypher(
+                "UNWIND $rows AS r MATCH (l:Link {id: r.id}) SET l.source = r.source, l.target = r.target",
+                Some(json!({ "rows": rows })),
+            )?;
+        }
+        if !self.pending_deletes.is_empty() {
+            let ids: Vec<_> = self.pending_deletes.drain(..).collect();
+            self.client.execute_cypher(
+                "UNWIND $ids AS id MATCH (l:Link {id: id}) DELETE l",
+                Some(json!({ "ids": ids })),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn queue_create(&mut self, id: i64) -> Result<()> {
+        self.pending_creates.push(id);
+        if self.pending_creates.len() >= self.batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn queue_update(&mut self, id: i64, source: i64, target: i64) -> Result<()> {
+        self.pending_updates.push((id, source, target));
+        if self.pending_updates.len() >= self.batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn queue_delete(&mut self, id: i64) -> Result<()> {
+        self.pending_deletes.push(id);
+        if self.pending_deletes.len() >= self.batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: LinkType> Sql for Batched<'_, T> {
+    fn create_table(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn drop_table(&mut self) -> Result<()> {
+        self.flush()?;
+        let _ = self
+            .client
+            .execute_cypher("MATCH (l:Link) DETACH DELETE l", None);
+        self.client.reset_next_id();
+        Ok(())
+    }
+}
+
+impl<'a, T: LinkType> Links<T> for Exclusive<Batched<'a, T>> {
+    fn constants(&self) -> &LinksConstants<T> {
+        self.client.constants()
+    }
+
+    fn count_links(&self, query: &[T]) -> T {
+        let any = self.constants().any;
+
+        let cypher = if query.is_empty() {
+            "MATCH (l:Link) RETURN count(l) as count".to_string()
+        } else if query.len() == 1 {
+            if query[0] == any {
+                "MATCH (l:Link) RETURN count(l) as count".to_string()
+            } else {
+                format!(
+                    "MATCH (l:Link {{id: {}}}) RETURN count(l) as count",
+                    query[0]
+                )
+            }
+        } else if query.len() == 3 {
+            let mut conditions = Vec::new();
+
+            if query[0] != any {
+                conditions.push(format!("l.id = {}", query[0]));
+            }
+            if query[1] != any {
+                conditions.push(format!("l.source = {}", query[1]));
+            }
+            if query[2] != any {
+                conditions.push(format!("l.target = {}", query[2]));
+            }
+
+            if conditions.is_empty() {
+                "MATCH (l:Link) RETURN count(l) as count".to_string()
+            } else {
+                format!(
+                    "MATCH (l:Link) WHERE {} RETURN count(l) as count",
+                    conditions.join(" AND ")
+                )
+            }
+        } else {
+            panic!("Constraints violation: size of query neither 1 nor 3")
+        };
+
+        match self.client.execute_cypher(&cypher, None) {
+            Ok(response) => {
+                if let Some(result) = response.results.first() {
+                    if let Some(row) = result.data.first() {
+                        if let Some(val) = row.row.first() {
+                            let count = val.as_i64().unwrap_or(0);
+                            return count.try_into().unwrap_or(T::ZERO);
+                        }
+                    }
+                }
+                T::ZERO
+            }
+            Err(_) => T::ZERO,
+        }
+    }
+
+    fn create_links(
+        &mut self,
+        _query: &[T],
+        handler: WriteHandler<T>,
+    ) -> std::result::Result<Flow, Error<T>> {
+        let next_id = self.client.fetch_next_id();
+        let _ = self.queue_create(next_id);
+
+        Ok(handler(
+            Link::nothing(),
+            Link::new(next_id.try_into().unwrap_or(T::ZERO), T::ZERO, T::ZERO),
+        ))
+    }
+
+    fn each_links(&self, query: &[T], handler: ReadHandler<T>) -> Flow {
+        let any = self.constants().any;
+
+        let cypher = if query.is_empty() {
+            "MATCH (l:Link) RETURN l.id as id, l.source as source, l.target as target".to_string()
+        } else if query.len() == 1 {
+            if query[0] == any {
+                "MATCH (l:Link) RETURN l.id as id, l.source as source, l.target as target"
+                    .to_string()
+            } else {
+                format!(
+                    "MATCH (l:Link {{id: {}}}) RETURN l.id as id, l.source as source, l.target as target",
+                    query[0]
+                )
+            }
+        } else if query.len() == 3 {
+            let mut conditions = Vec::new();
+
+            if query[0] != any {
+                conditions.push(format!("l.id = {}", query[0]));
+            }
+            if query[1] != any {
+                conditions.push(format!("l.source = {}", query[1]));
+            }
+            if query[2] != any {
+                conditions.push(format!("l.target = {}", query[2]));
+            }
+
+            if conditions.is_empty() {
+                "MATCH (l:Link) RETURN l.id as id, l.source as source, l.target as target"
+                    .to_string()
+            } else {
+                format!(
+                    "MATCH (l:Link) WHERE {} RETURN l.id as id, l.source as source, l.target as target",
+                    conditions.join(" AND ")
+                )
+            }
+        } else {
+            panic!("Constraints violation: size of query neither 1 nor 3")
+        };
+
+        match self.client.execute_cypher(&cypher, None) {
+            Ok(response) => {
+                if let Some(result) = response.results.first() {
+                    for row in &result.data {
+                        if row.row.len() >= 3 {
+                            let id = row.row[0].as_i64().unwrap_or(0);
+                            let source = row.row[1].as_i64().unwrap_or(0);
+                            let target = row.row[2].as_i64().unwrap_or(0);
+
+                            if let Flow::Break = handler(Link::new(
+                                id.try_into().unwrap_or(T::ZERO),
+                                source.try_into().unwrap_or(T::ZERO),
+                                target.try_into().unwrap_or(T::ZERO),
+                            )) {
+                                return Flow::Break;
+                            }
+                        }
+                    }
+                }
+                Flow::Continue
+            }
+            Err(_) => Flow::Continue,
+        }
+    }
+
+    fn update_links(
+        &mut self,
+        query: &[T],
+        change: &[T],
+        handler: WriteHandler<T>,
+    ) -> std::result::Result<Flow, Error<T>> {
+        let id = query[0];
+        let source = change[1];
+        let target = change[2];
+
+        // Matches `Client::update_links`'s contract: it never errors on a
+        // missing id, it just reports a zeroed "before" link.
+        let before = self.get_link(id).unwrap_or(Link::new(id, T::ZERO, T::ZERO));
+
+        let _ = self.queue_update(id.as_i64(), source.as_i64(), target.as_i64());
+
+        Ok(handler(before, Link::new(id, source, target)))
+    }
+
+    fn delete_links(
+        &mut self,
+        query: &[T],
+        handler: WriteHandler<T>,
+    ) -> std::result::Result<Flow, Error<T>> {
+        let id = query[0];
+
+        // Matches `Client::delete_links`'s contract: a delete on an id that
+        // was never created is an error here too, not a silent no-op. An id
+        // still buffered in `pending_creates` counts as created.
+        let before = if self.pending_creates.contains(&id.as_i64()) {
+            Link::new(id, T::ZERO, T::ZERO)
+        } else {
+            self.get_link(id).ok_or(Error::<T>::NotExists(id))?
+        };
+
+        let _ = self.queue_delete(id.as_i64());
+
+        Ok(handler(before, Link::nothing()))
+    }
+}
+
+impl<'a, T: LinkType> Doublets<T> for Exclusive<Batched<'a, T>> {
+    fn get_link(&self, index: T) -> Option<Link<T>> {
+        match self.client.execute_cypher(
+            "MATCH (l:Link {id: $id}) RETURN l.source as source, l.target as target",
+            Some(json!({"id": index.as_i64()})),
+        ) {
+            Ok(response) => {
+                if let Some(result) = response.results.first() {
+                    if let Some(row) = result.data.first() {
+                        if row.row.len() >= 2 {
+                            let source = row.row[0].as_i64().unwrap_or(0);
+                            let target = row.row[1].as_i64().unwrap_or(0);
+                            return Some(Link::new(
+                                index,
+                                source.try_into().unwrap_or(T::ZERO),
+                                target.try_into().unwrap_or(T::ZERO),
+                            ));
+                        }
+                    }
+                }
+                None
+            }
+            Err(_) => None,
+        }
+    }
+}
@@ -10,9 +10,18 @@
 //!
 //! - **[`doublets_benched`]** - Doublets storage backend implementations
 //! - **[`neo4j_benched`]** - Neo4j storage backend implementations
+//! - **[`sqlite_benched`]** - SQLite storage backend implementation
+//! - **[`rocksdb_benched`]** - RocksDB storage backend implementation
 
 mod doublets_benched;
 mod neo4j_benched;
+mod rocksdb_benched;
+mod sqlite_benched;
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
 
 use crate::Fork;
 
@@ -21,6 +30,8 @@ use crate::Fork;
 /// Provides the setup/teardown lifecycle for benchmark iterations:
 /// - [`Benched::setup`] - Initialize the storage backend
 /// - [`Benched::fork`] - Create an isolated environment for a single iteration
+/// - [`Benched::fork_concurrent`] - Create `n` isolated forks for a
+///   multi-worker iteration
 /// - [`Benched::unfork`] - Clean up after the iteration
 pub trait Benched: Sized {
     /// Builder parameter type for constructing this storage.
@@ -36,9 +47,88 @@ pub trait Benched: Sized {
         Fork(self)
     }
 
+    /// Creates `n` isolated forks for a single multi-worker benchmark
+    /// iteration, one per worker thread.
+    ///
+    /// Unlike [`fork`][Benched::fork], which hands back one `&mut`-borrowed
+    /// fork for a single-threaded iteration, this hands back `n` forks meant
+    /// to be driven concurrently, each from its own worker thread. The
+    /// default implementation wraps `self` behind one shared `Mutex` and
+    /// hands every fork a handle to it -- sound for any backend, and correct
+    /// for the `Doublets_*`/`Sqlite_*` backends this default is meant for,
+    /// which have no concept of a session independent from the store itself:
+    /// the lock means no two workers are ever inside the store at the same
+    /// instant, so a volatile or file-mapped store can't observe a torn
+    /// write, at the cost of serializing those workers against each other.
+    /// Backends with an independent per-connection session of their own --
+    /// Neo4j's `Transaction`/`Batched`, which can each open another session
+    /// on the same `Client` -- override this to hand back `n` fully
+    /// independent forks instead; see `src/benched/neo4j_benched.rs`.
+    fn fork_concurrent(&mut self, n: usize) -> Vec<ConcurrentFork<'_, Self>>
+    where
+        Self: Send,
+    {
+        let shared = Arc::new(Mutex::new(self));
+        let remaining = Arc::new(AtomicUsize::new(n));
+        (0..n)
+            .map(|_| ConcurrentFork::Shared(Arc::clone(&shared), Arc::clone(&remaining)))
+            .collect()
+    }
+
     /// Clean up after a benchmark iteration.
     ///
     /// # Safety
     /// This method may perform unsafe operations like clearing all data.
     unsafe fn unfork(&mut self);
 }
+
+/// One of the `n` forks [`Benched::fork_concurrent`] hands out.
+///
+/// `Shared` forks all contend for the same underlying store through a
+/// `Mutex`; `Independent` forks each own their store outright (e.g. their
+/// own Neo4j transaction) and never contend with their siblings at all.
+/// Either way, [`Benched::unfork`] runs exactly once per underlying store --
+/// for `Shared`, that's when the last fork referencing it drops, not once
+/// per fork, since running it per-fork would tear down data the remaining
+/// forks are still using; for `Independent`, each fork's own drop is safe to
+/// unfork on its own because nothing else shares it.
+pub enum ConcurrentFork<'a, B: Benched> {
+    Shared(Arc<Mutex<&'a mut B>>, Arc<AtomicUsize>),
+    Independent(B),
+}
+
+impl<'a, B: Benched> ConcurrentFork<'a, B> {
+    /// Runs `f` against this fork's store -- serialized against its
+    /// siblings if [`Shared`][ConcurrentFork::Shared], uncontended if
+    /// [`Independent`][ConcurrentFork::Independent].
+    pub fn with<R>(&mut self, f: impl FnOnce(&mut B) -> R) -> R {
+        match self {
+            ConcurrentFork::Shared(shared, _) => {
+                let mut guard = shared.lock().expect("concurrent fork lock poisoned");
+                f(&mut guard)
+            }
+            ConcurrentFork::Independent(store) => f(store),
+        }
+    }
+}
+
+impl<'a, B: Benched> Drop for ConcurrentFork<'a, B> {
+    fn drop(&mut self) {
+        match self {
+            ConcurrentFork::Shared(shared, remaining) => {
+                // `Arc::strong_count` can't tell "I'm the last one" apart
+                // from "I and one sibling are both dropping right now" --
+                // both would observe `strong_count == 2` before either
+                // releases its own reference. Count down explicitly instead:
+                // exactly one dropping fork ever sees the decrement land on
+                // `1`, no matter how many siblings drop concurrently.
+                if remaining.fetch_sub(1, Ordering::AcqRel) == 1 {
+                    if let Ok(mut guard) = shared.lock() {
+                        unsafe { guard.unfork() };
+                    }
+                }
+            }
+            ConcurrentFork::Independent(store) => unsafe { store.unfork() },
+        }
+    }
+}
@@ -9,6 +9,7 @@
 //! |-----------------------------|-----------------|----------------------------------|
 //! | `Exclusive<Client>`         | Non-transaction | Direct HTTP API calls            |
 //! | `Exclusive<Transaction>`    | Transaction     | Transaction wrapper (same impl)  |
+//! | `Exclusive<Batched>`        | Batched         | Buffers writes, flushes in bulk  |
 //!
 //! ## Implementation Details
 //!
@@ -21,8 +22,8 @@
 
 use doublets::data::LinkType;
 
-use super::Benched;
-use crate::{Client, Exclusive, Fork, Sql, Transaction};
+use super::{Benched, ConcurrentFork};
+use crate::{Batched, Client, Exclusive, Fork, Sql, Transaction};
 
 /// Neo4j client (non-transactional mode).
 ///
@@ -80,8 +81,70 @@ impl<'a, T: LinkType> Benched for Exclusive<Transaction<'a, T>> {
         Fork(self)
     }
 
+    /// Opens `n` more transactions on the same `Client`, each a fully
+    /// independent server-side transaction -- `Client` already hands out a
+    /// fresh TCP connection per request, so concurrent transactions on it
+    /// don't contend with each other the way a shared `Doublets_*` store
+    /// would. No `Mutex` involved, so no serialization across workers.
+    fn fork_concurrent(&mut self, n: usize) -> Vec<ConcurrentFork<'_, Self>> {
+        let client = self.client();
+        (0..n)
+            .filter_map(|_| Transaction::new(client).ok())
+            .map(|transaction| unsafe { Exclusive::new(transaction) })
+            .map(ConcurrentFork::Independent)
+            .collect()
+    }
+
     unsafe fn unfork(&mut self) {
         // Clean up after benchmark iteration
         let _ = self.drop_table();
     }
 }
+
+/// Neo4j batched wrapper.
+///
+/// ## Setup
+/// ```rust,ignore
+/// let client = connect()?;
+/// let batched = Exclusive::<Batched<'_, usize>>::setup((&client, 100))?;
+/// ```
+///
+/// ## Fork Behavior
+/// Cleans up any existing data before each iteration to ensure isolation.
+///
+/// ## Cleanup
+/// Flushes any buffered create/update/delete operations, then executes
+/// `MATCH (l:Link) DETACH DELETE l` to remove all nodes.
+impl<'a, T: LinkType> Benched for Exclusive<Batched<'a, T>> {
+    type Builder<'b> = (&'a Client<T>, usize);
+
+    fn setup(builder: Self::Builder<'_>) -> crate::Result<Self> {
+        let (client, batch_size) = builder;
+        unsafe { Ok(Exclusive::new(Batched::new(client, batch_size))) }
+    }
+
+    fn fork(&mut self) -> Fork<Self> {
+        // Clean up any existing data (and flush anything left buffered)
+        // before benchmark to ensure isolation
+        let _ = self.drop_table();
+        Fork(self)
+    }
+
+    /// Opens `n` more batches on the same `Client`, each with its own
+    /// buffers -- fully independent of each other, like
+    /// `Exclusive<Transaction<'_, T>>::fork_concurrent`.
+    fn fork_concurrent(&mut self, n: usize) -> Vec<ConcurrentFork<'_, Self>> {
+        let client = self.client();
+        let batch_size = self.batch_size();
+        (0..n)
+            .map(|_| unsafe { Exclusive::new(Batched::new(client, batch_size)) })
+            .map(ConcurrentFork::Independent)
+            .collect()
+    }
+
+    unsafe fn unfork(&mut self) {
+        // Flush any buffered operations, then clean up after benchmark
+        // iteration
+        let _ = self.drop_table();
+    }
+}
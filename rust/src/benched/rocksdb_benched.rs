@@ -0,0 +1,42 @@
+//! # RocksDB Benched Implementation
+//!
+//! This module contains the [`Benched`] trait implementation for
+//! `Doublets_RocksDB`, the persistent embedded LSM-tree backend that sits
+//! between the volatile in-memory Doublets stores and the full Neo4j graph
+//! server on the persistence spectrum.
+//!
+//! ## Implementation Details
+//!
+//! Unlike `Sqlite`, there's no schema to (re-)create on `fork()`; `unfork()`
+//! calls [`RocksDb::clear`] to delete every key and reset the id counter for
+//! the next iteration.
+
+use doublets::data::LinkType;
+
+use super::Benched;
+use crate::{Exclusive, Fork, RocksDb};
+
+/// RocksDB-backed store, opened at the path passed to [`RocksDb::open`].
+///
+/// ## Setup
+/// ```rust,ignore
+/// let store = Exclusive::<RocksDb<usize>>::setup("rocksdb_bench")?;
+/// ```
+///
+/// ## Cleanup
+/// Deletes every key and resets the id counter between iterations.
+impl<T: LinkType> Benched for Exclusive<RocksDb<T>> {
+    type Builder<'a> = &'a str;
+
+    fn setup(path: Self::Builder<'_>) -> crate::Result<Self> {
+        unsafe { Ok(Exclusive::new(RocksDb::open(path)?)) }
+    }
+
+    fn fork(&mut self) -> Fork<Self> {
+        Fork(self)
+    }
+
+    unsafe fn unfork(&mut self) {
+        let _ = self.clear();
+    }
+}
@@ -0,0 +1,45 @@
+//! # SQLite Benched Implementation
+//!
+//! This module contains the [`Benched`] trait implementation shared by both
+//! SQLite storage backends (`Sqlite_Volatile`/`Sqlite_NonVolatile`). The two
+//! only differ in the path passed to [`Sqlite::open`]: `":memory:"` opens a
+//! volatile, in-process database, anything else opens (or creates) a file.
+//!
+//! ## Implementation Details
+//!
+//! Mirrors the Neo4j `Client` lifecycle: `fork()` (re-)creates the `links`
+//! table so each iteration starts from a known schema, and `unfork()`
+//! executes `DELETE FROM links` to clear rows for the next iteration.
+
+use doublets::data::LinkType;
+
+use super::Benched;
+use crate::{Exclusive, Fork, Sql, Sqlite};
+
+/// SQLite-backed store (volatile or non-volatile, depending on the path
+/// passed to [`Sqlite::open`]).
+///
+/// ## Setup
+/// ```rust,ignore
+/// let volatile = Exclusive::<Sqlite<usize>>::setup(":memory:")?;
+/// let non_volatile = Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db")?;
+/// ```
+///
+/// ## Cleanup
+/// Executes `DELETE FROM links` to remove all rows between iterations.
+impl<T: LinkType> Benched for Exclusive<Sqlite<T>> {
+    type Builder<'a> = &'a str;
+
+    fn setup(path: Self::Builder<'_>) -> crate::Result<Self> {
+        unsafe { Ok(Exclusive::new(Sqlite::open(path)?)) }
+    }
+
+    fn fork(&mut self) -> Fork<Self> {
+        let _ = self.create_table();
+        Fork(self)
+    }
+
+    unsafe fn unfork(&mut self) {
+        let _ = self.drop_table();
+    }
+}
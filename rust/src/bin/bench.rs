@@ -0,0 +1,463 @@
+#![feature(allocator_api, generic_associated_types)]
+
+//! # Standalone Bencher
+//!
+//! `benches/bench.rs` is a full Criterion harness -- statistical sampling,
+//! warm-up iterations, HTML reports, the whole nightly-gated `criterion`
+//! machinery. That's the right tool for a dashboard-tracked run, but
+//! overkill for "does this operation still work, and roughly how fast is
+//! it" in a CI script or a quick local loop. This binary skips Criterion
+//! entirely: it reuses the exact same [`connect`]/[`Benched::setup`]/
+//! [`Benched::fork`] lifecycle every benchmark module already drives,
+//! times [`LINK_COUNT`] operations (`update`: at most one per background
+//! link; `each_identity`/`each_concrete`/`each_outgoing`/`each_incoming`:
+//! exactly one per background link) with a single `Instant::now()` pair per
+//! `(backend, operation)` cell, and prints a compact table -- no
+//! statistics, just elapsed time and throughput.
+//!
+//! ## Usage
+//!
+//! ```text
+//! bench [OPERATIONS] [BACKENDS] [--backend NAME]... [--scenario NAME]...
+//!       [--background-links N] [--json[=PATH]]
+//! ```
+//!
+//! `OPERATIONS` and `BACKENDS` are comma-separated, positional, and both
+//! optional (omit or pass `all` to run everything). `OPERATIONS` is drawn
+//! from [`OPERATIONS`]: `create`, `update`, `delete`, `each_all`,
+//! `each_identity`, `each_concrete`, `each_outgoing`, `each_incoming`.
+//! `BACKENDS` accepts either a canonical backend id
+//! (`Doublets_United_Volatile`) or the same name with underscores dropped
+//! (`DoubletsUnitedVolatile`), matched case-insensitively -- see
+//! [`canonical_backend`]. `--backend NAME`/`--scenario NAME` are repeatable
+//! flag equivalents of the positional lists -- when either is given at
+//! least once, it replaces that list's "all" default instead of adding to
+//! it. `--background-links N` overrides `*BACKGROUND_LINKS` for this run
+//! only, without the `BENCHMARK_BACKGROUND_LINKS` env var. With no
+//! `--json`, results print as a compact table; `--json` prints a JSON
+//! array to stdout instead, and `--json=PATH` writes it to `PATH` (table
+//! still goes to stdout).
+//!
+//! ```text
+//! $ bench create,each_identity Doublets_United_Volatile,SqliteVolatile
+//! BACKEND                     OPERATION       ITERATIONS   ELAPSED_MS   OPS_PER_SEC
+//! Doublets_United_Volatile     create                 10         0.1      100000.0
+//! Doublets_United_Volatile     each_identity           10         0.0      500000.0
+//! Sqlite_Volatile              create                 10         2.3        4347.8
+//! Sqlite_Volatile              each_identity           10         1.1        9090.9
+//!
+//! $ bench --scenario each_outgoing --backend Doublets_Split_NonVolatile --background-links 50000
+//! ```
+
+use std::{
+    alloc::Global,
+    env, fs,
+    time::{Duration, Instant},
+};
+
+use doublets::{
+    data::{Flow, LinksConstants},
+    mem::{Alloc, FileMapped},
+    parts::LinkPart,
+    split::{self, DataPart, IndexPart},
+    unit, Doublets,
+};
+use linksneo4j::{
+    connect, Batched, Benched, Client, Exclusive, RocksDb, Sqlite, Transaction, BACKGROUND_LINKS,
+    LINK_COUNT,
+};
+use serde::Serialize;
+
+/// Operations this binary knows how to time, in the order the `create`
+/// benchmark module documents them in `lib.rs`'s "Common Interface" table.
+const OPERATIONS: &[&str] = &[
+    "create",
+    "update",
+    "delete",
+    "each_all",
+    "each_identity",
+    "each_concrete",
+    "each_outgoing",
+    "each_incoming",
+];
+
+/// Canonical backend ids this binary can set up directly, the same ids
+/// [`linksneo4j::selector::SELECTOR`] matches against elsewhere in the
+/// crate. `Neo4j_Concurrent/{level}` and the non-CRUD benchmarks (bulk,
+/// scaling, snapshot, load) are out of scope here -- this binary covers
+/// exactly the core `Doublets<T>` operations, not the whole suite.
+const BACKENDS: &[&str] = &[
+    "Doublets_United_Volatile",
+    "Doublets_United_NonVolatile",
+    "Doublets_Split_Volatile",
+    "Doublets_Split_NonVolatile",
+    "Doublets_RocksDB",
+    "Sqlite_Volatile",
+    "Sqlite_NonVolatile",
+    "Neo4j_NonTransaction",
+    "Neo4j_Transaction",
+    "Neo4j_Batched",
+];
+
+/// One `(backend, operation)` timing result.
+#[derive(Serialize)]
+struct Row {
+    backend: String,
+    operation: String,
+    iterations: usize,
+    elapsed_ms: f64,
+    ops_per_sec: f64,
+}
+
+impl Row {
+    fn new(backend: &str, operation: &str, iterations: usize, elapsed: Duration) -> Self {
+        let elapsed_ms = elapsed.as_secs_f64() * 1_000.0;
+        Self {
+            backend: backend.to_string(),
+            operation: operation.to_string(),
+            iterations,
+            elapsed_ms,
+            ops_per_sec: iterations as f64 / elapsed.as_secs_f64(),
+        }
+    }
+}
+
+/// Matches a CLI-supplied backend name against a canonical id from
+/// [`BACKENDS`], ignoring underscores and case -- so both
+/// `Doublets_United_Volatile` and `DoubletsUnitedVolatile` resolve to the
+/// same backend.
+fn canonical_backend(name: &str) -> Option<&'static str> {
+    let normalize = |s: &str| s.chars().filter(|c| *c != '_').collect::<String>().to_lowercase();
+    let target = normalize(name);
+    BACKENDS.iter().copied().find(|backend| normalize(backend) == target)
+}
+
+/// Parses a comma-separated positional argument against `known`, falling
+/// back to every entry in `known` if the argument is absent or `"all"`.
+fn parse_list<'a>(arg: Option<&str>, known: &'a [&'a str], canonicalize: impl Fn(&str) -> Option<&'a str>) -> Vec<&'a str> {
+    match arg {
+        None => known.to_vec(),
+        Some(raw) if raw.eq_ignore_ascii_case("all") => known.to_vec(),
+        Some(raw) => raw
+            .split(',')
+            .filter_map(|entry| canonicalize(entry.trim()))
+            .collect(),
+    }
+}
+
+/// Runs `operation` on `fork`, preparing `background_links` points first
+/// where the operation needs existing data to act on -- the same
+/// prep/time split `benches/benchmarks/doublets` uses, just without
+/// Criterion's repeated sampling. `background_links` is `--background-links`
+/// if given, else `*BACKGROUND_LINKS`, the same override-or-default
+/// resolution every flag in [`Args`] uses.
+fn run_operation<B: Doublets<usize>>(
+    store: &mut B,
+    operation: &str,
+    background_links: usize,
+) -> Option<(Duration, usize)> {
+    let handler = |_| Flow::Continue;
+    let any = LinksConstants::new().any;
+    let iterations = *LINK_COUNT;
+    let background = background_links;
+
+    match operation {
+        "create" => {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                let _ = store.create_point();
+            }
+            Some((start.elapsed(), iterations))
+        }
+        "update" => {
+            for _ in 0..background {
+                let _ = store.create_point();
+            }
+            let count = iterations.min(background);
+            let start = Instant::now();
+            for id in 1..=count {
+                let _ = store.update(id, id, id);
+            }
+            Some((start.elapsed(), count))
+        }
+        "delete" => {
+            for _ in 0..background + iterations {
+                let _ = store.create_point();
+            }
+            let start = Instant::now();
+            for id in (background + 1..=background + iterations).rev() {
+                let _ = store.delete(id);
+            }
+            Some((start.elapsed(), iterations))
+        }
+        "each_all" => {
+            for _ in 0..background {
+                let _ = store.create_point();
+            }
+            let start = Instant::now();
+            for _ in 0..iterations {
+                store.each(handler);
+            }
+            Some((start.elapsed(), iterations))
+        }
+        "each_identity" => {
+            for _ in 0..background {
+                let _ = store.create_point();
+            }
+            let start = Instant::now();
+            for id in 1..=background {
+                store.each_by([id, any, any], handler);
+            }
+            Some((start.elapsed(), background))
+        }
+        "each_concrete" => {
+            for _ in 0..background {
+                let _ = store.create_point();
+            }
+            let start = Instant::now();
+            for id in 1..=background {
+                store.each_by([any, id, id], handler);
+            }
+            Some((start.elapsed(), background))
+        }
+        "each_outgoing" => {
+            for _ in 0..background {
+                let _ = store.create_point();
+            }
+            let start = Instant::now();
+            for id in 1..=background {
+                store.each_by([any, id, any], handler);
+            }
+            Some((start.elapsed(), background))
+        }
+        "each_incoming" => {
+            for _ in 0..background {
+                let _ = store.create_point();
+            }
+            let start = Instant::now();
+            for id in 1..=background {
+                store.each_by([any, any, id], handler);
+            }
+            Some((start.elapsed(), background))
+        }
+        _ => None,
+    }
+}
+
+/// Sets up `backend` fresh, forks it, times `operation` on the fork, and
+/// appends the result to `rows`. `unfork` runs implicitly when the
+/// returned [`linksneo4j::Fork`] drops, the same lifecycle
+/// `bench!`-driven benchmarks rely on.
+fn bench<B: Benched + Doublets<usize>>(
+    rows: &mut Vec<Row>,
+    backend: &str,
+    operation: &str,
+    background_links: usize,
+    mut benched: B,
+) {
+    let mut fork = Benched::fork(&mut benched);
+    if let Some((elapsed, count)) = run_operation(&mut *fork, operation, background_links) {
+        rows.push(Row::new(backend, operation, count, elapsed));
+    }
+}
+
+fn run_backend(rows: &mut Vec<Row>, backend: &str, operation: &str, background_links: usize) {
+    match backend {
+        "Doublets_United_Volatile" => {
+            if let Ok(store) = unit::Store::<usize, Alloc<LinkPart<_>, Global>>::setup(()) {
+                bench(rows, backend, operation, background_links, store);
+            }
+        }
+        "Doublets_United_NonVolatile" => {
+            if let Ok(store) = unit::Store::<usize, FileMapped<LinkPart<_>>>::setup("united.links") {
+                bench(rows, backend, operation, background_links, store);
+            }
+        }
+        "Doublets_Split_Volatile" => {
+            if let Ok(store) =
+                split::Store::<usize, Alloc<DataPart<_>, _>, Alloc<IndexPart<_>, _>>::setup(())
+            {
+                bench(rows, backend, operation, background_links, store);
+            }
+        }
+        "Doublets_Split_NonVolatile" => {
+            if let Ok(store) = split::Store::<usize, FileMapped<_>, FileMapped<_>>::setup((
+                "split_index.links",
+                "split_data.links",
+            )) {
+                bench(rows, backend, operation, background_links, store);
+            }
+        }
+        "Doublets_RocksDB" => {
+            if let Ok(store) = Exclusive::<RocksDb<usize>>::setup("rocksdb_bench") {
+                bench(rows, backend, operation, background_links, store);
+            }
+        }
+        "Sqlite_Volatile" => {
+            if let Ok(store) = Exclusive::<Sqlite<usize>>::setup(":memory:") {
+                bench(rows, backend, operation, background_links, store);
+            }
+        }
+        "Sqlite_NonVolatile" => {
+            if let Ok(store) = Exclusive::<Sqlite<usize>>::setup("sqlite_bench.db") {
+                bench(rows, backend, operation, background_links, store);
+            }
+        }
+        "Neo4j_NonTransaction" => {
+            if let Ok(store) = Exclusive::<Client<usize>>::setup(()) {
+                bench(rows, backend, operation, background_links, store);
+            }
+        }
+        "Neo4j_Transaction" => {
+            if let Ok(client) = connect::<usize>() {
+                if let Ok(store) = Exclusive::<Transaction<'_, usize>>::setup(&client) {
+                    bench(rows, backend, operation, background_links, store);
+                }
+            }
+        }
+        "Neo4j_Batched" => {
+            if let Ok(client) = connect::<usize>() {
+                if let Ok(store) =
+                    Exclusive::<Batched<'_, usize>>::setup((&client, linksneo4j::neo4j_batch_size()))
+                {
+                    bench(rows, backend, operation, background_links, store);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `--backend NAME` / `--scenario NAME` select a single backend/operation
+/// each without recompiling (repeatable; each occurrence adds to the set).
+/// They compose with the positional comma-list form: `bench create
+/// --backend Doublets_Split_NonVolatile` runs `create` against just that
+/// backend. `--background-links N` overrides `*BACKGROUND_LINKS` for this
+/// run only, without the `BENCHMARK_BACKGROUND_LINKS` env var. `--json`
+/// (stdout) or `--json=PATH` (file); any other leading-`--` argument is
+/// ignored rather than rejected, so this stays forgiving for a quick local
+/// loop.
+struct Args {
+    operations: Vec<&'static str>,
+    backends: Vec<&'static str>,
+    background_links: usize,
+    json_path: Option<String>,
+    json_stdout: bool,
+}
+
+fn parse_args() -> Args {
+    let raw: Vec<String> = env::args().skip(1).collect();
+    let mut positional = Vec::new();
+    let mut backend_flags = Vec::new();
+    let mut scenario_flags = Vec::new();
+    let mut background_links = None;
+    let mut json_path = None;
+    let mut json_stdout = false;
+
+    let mut i = 0;
+    while i < raw.len() {
+        let arg = raw[i].as_str();
+        if arg == "--json" {
+            json_stdout = true;
+        } else if let Some(path) = arg.strip_prefix("--json=") {
+            json_path = Some(path.to_string());
+        } else if let Some(value) = arg.strip_prefix("--backend=") {
+            backend_flags.push(value.to_string());
+        } else if arg == "--backend" {
+            i += 1;
+            if let Some(value) = raw.get(i) {
+                backend_flags.push(value.clone());
+            }
+        } else if let Some(value) = arg.strip_prefix("--scenario=") {
+            scenario_flags.push(value.to_string());
+        } else if arg == "--scenario" {
+            i += 1;
+            if let Some(value) = raw.get(i) {
+                scenario_flags.push(value.clone());
+            }
+        } else if let Some(value) = arg.strip_prefix("--background-links=") {
+            background_links = value.parse().ok();
+        } else if arg == "--background-links" {
+            i += 1;
+            background_links = raw.get(i).and_then(|value| value.parse().ok());
+        } else if arg.starts_with("--") {
+            // Unrecognized flag: ignored.
+        } else {
+            positional.push(arg.to_string());
+        }
+        i += 1;
+    }
+
+    // `--scenario`/`--backend` take priority over the positional comma-list
+    // form when given, rather than unioning with its "all" default.
+    let operations = if scenario_flags.is_empty() {
+        parse_list(positional.first().map(String::as_str), OPERATIONS, |op| {
+            OPERATIONS.iter().copied().find(|&known| known == op)
+        })
+    } else {
+        scenario_flags
+            .iter()
+            .filter_map(|op| OPERATIONS.iter().copied().find(|&known| known == op))
+            .collect()
+    };
+
+    let backends = if backend_flags.is_empty() {
+        parse_list(positional.get(1).map(String::as_str), BACKENDS, canonical_backend)
+    } else {
+        backend_flags
+            .iter()
+            .filter_map(|name| canonical_backend(name))
+            .collect()
+    };
+
+    Args {
+        operations,
+        backends,
+        background_links: background_links.unwrap_or(*BACKGROUND_LINKS),
+        json_path,
+        json_stdout,
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    println!(
+        "{:<28} {:<15} {:>10} {:>12} {:>13}",
+        "BACKEND", "OPERATION", "ITERATIONS", "ELAPSED_MS", "OPS_PER_SEC"
+    );
+    for row in rows {
+        println!(
+            "{:<28} {:<15} {:>10} {:>12.1} {:>13.1}",
+            row.backend, row.operation, row.iterations, row.elapsed_ms, row.ops_per_sec
+        );
+    }
+}
+
+fn main() {
+    let args = parse_args();
+    let mut rows = Vec::new();
+
+    for &backend in &args.backends {
+        for &operation in &args.operations {
+            run_backend(&mut rows, backend, operation, args.background_links);
+        }
+    }
+
+    print_table(&rows);
+
+    if args.json_stdout {
+        match serde_json::to_string_pretty(&rows) {
+            Ok(json) => println!("{json}"),
+            Err(err) => eprintln!("failed to serialize results as JSON: {err}"),
+        }
+    }
+    if let Some(path) = args.json_path {
+        match serde_json::to_string_pretty(&rows) {
+            Ok(json) => {
+                if let Err(err) = fs::write(&path, json) {
+                    eprintln!("failed to write {path}: {err}");
+                }
+            }
+            Err(err) => eprintln!("failed to serialize results as JSON: {err}"),
+        }
+    }
+}
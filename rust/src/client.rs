@@ -1,7 +1,8 @@
 use std::{
+    collections::HashMap,
     io::{Read, Write},
     net::TcpStream,
-    sync::atomic::{AtomicI64, Ordering},
+    sync::atomic::{AtomicI64, AtomicU64, Ordering},
 };
 
 use doublets::{
@@ -20,6 +21,10 @@ pub struct Client<T: LinkType> {
     auth: String,
     constants: LinksConstants<T>,
     next_id: AtomicI64,
+    round_trips: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    rows_returned: AtomicU64,
 }
 
 #[derive(Debug, Serialize)]
@@ -27,7 +32,7 @@ struct CypherRequest {
     statements: Vec<Statement>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct Statement {
     statement: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -98,6 +103,41 @@ impl<T: LinkType> Client<T> {
         self.next_id.store(1, Ordering::SeqCst);
     }
 
+    /// Number of HTTP requests sent via [`Self::raw_request`] so far.
+    pub fn round_trips(&self) -> u64 {
+        self.round_trips.load(Ordering::Relaxed)
+    }
+
+    /// Total bytes sent across all [`Self::raw_request`] calls so far.
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent.load(Ordering::Relaxed)
+    }
+
+    /// Total bytes received across all [`Self::raw_request`] calls so far.
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received.load(Ordering::Relaxed)
+    }
+
+    /// Total rows returned across every `CypherResponse` this client has
+    /// parsed so far. The legacy transactional HTTP API doesn't expose a
+    /// per-query "db hits" count outside of query profiling, but it always
+    /// returns the result rows themselves, so this is the real read-volume
+    /// signal it makes available -- counted here rather than at each of
+    /// [`Self::execute_cypher`]/[`Self::execute_in_transaction`]/
+    /// [`Self::commit_transaction`]'s call sites.
+    pub fn rows_returned(&self) -> u64 {
+        self.rows_returned.load(Ordering::Relaxed)
+    }
+
+    fn record_rows(&self, response: &CypherResponse) {
+        let rows: u64 = response
+            .results
+            .iter()
+            .map(|result| result.data.len() as u64)
+            .sum();
+        self.rows_returned.fetch_add(rows, Ordering::Relaxed);
+    }
+
     pub fn new(uri: &str, user: &str, password: &str) -> Result<Self> {
         // Parse URI to extract host and port
         let uri = uri.replace("bolt://", "").replace("http://", "");
@@ -115,6 +155,10 @@ impl<T: LinkType> Client<T> {
             auth,
             constants: LinksConstants::new(),
             next_id: AtomicI64::new(1),
+            round_trips: AtomicU64::new(0),
+            bytes_sent: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+            rows_returned: AtomicU64::new(0),
         };
 
         // Create indexes (ignore errors if already exist)
@@ -149,20 +193,83 @@ impl<T: LinkType> Client<T> {
         Ok(client)
     }
 
-    /// Execute a Cypher query against Neo4j
+    /// Execute a Cypher query against Neo4j using the auto-commit
+    /// `/db/neo4j/tx/commit` endpoint.
     pub fn execute_cypher(&self, query: &str, params: Option<Value>) -> Result<CypherResponse> {
-        let request = CypherRequest {
-            statements: vec![Statement {
-                statement: query.to_string(),
-                parameters: params,
-            }],
-        };
+        let body = statements_body(&[Statement {
+            statement: query.to_string(),
+            parameters: params,
+        }])?;
+
+        let raw = self.raw_request("POST", "/db/neo4j/tx/commit", Some(&body))?;
+        let response = parse_cypher_response(&raw.body)?;
+        self.record_rows(&response);
+        Ok(response)
+    }
+
+    /// Open a new server-side transaction via `POST /db/neo4j/tx` and return
+    /// its id, parsed from the `Location` response header (e.g.
+    /// `http://host:port/db/neo4j/tx/42` -> `"42"`).
+    pub fn begin_transaction(&self) -> Result<String> {
+        let body = statements_body(&[])?;
+        let raw = self.raw_request("POST", "/db/neo4j/tx", Some(&body))?;
+
+        let location = raw
+            .headers
+            .get("location")
+            .ok_or("Neo4j response missing Location header for opened transaction")?;
+
+        location
+            .rsplit('/')
+            .next()
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_string)
+            .ok_or_else(|| format!("Could not parse transaction id from Location: {location}").into())
+    }
+
+    /// Run a statement against an already-open transaction via
+    /// `POST /db/neo4j/tx/{tx_id}`. This keeps the transaction open (and
+    /// refreshes its lease) without committing it.
+    pub fn execute_in_transaction(
+        &self,
+        tx_id: &str,
+        query: &str,
+        params: Option<Value>,
+    ) -> Result<CypherResponse> {
+        let body = statements_body(&[Statement {
+            statement: query.to_string(),
+            parameters: params,
+        }])?;
+
+        let raw = self.raw_request("POST", &format!("/db/neo4j/tx/{tx_id}"), Some(&body))?;
+        let response = parse_cypher_response(&raw.body)?;
+        self.record_rows(&response);
+        Ok(response)
+    }
 
-        let body = serde_json::to_string(&request).map_err(|e| e.to_string())?;
-        let path = "/db/neo4j/tx/commit";
+    /// Finalize an open transaction via `POST /db/neo4j/tx/{tx_id}/commit`.
+    pub fn commit_transaction(&self, tx_id: &str) -> Result<()> {
+        let body = statements_body(&[])?;
+        let raw = self.raw_request("POST", &format!("/db/neo4j/tx/{tx_id}/commit"), Some(&body))?;
+        let response = parse_cypher_response(&raw.body)?;
+        self.record_rows(&response);
+        Ok(())
+    }
+
+    /// Abort an open transaction via `DELETE /db/neo4j/tx/{tx_id}`.
+    pub fn rollback_transaction(&self, tx_id: &str) -> Result<()> {
+        self.raw_request("DELETE", &format!("/db/neo4j/tx/{tx_id}"), None)?;
+        Ok(())
+    }
+
+    /// Send a raw HTTP request to the Neo4j HTTP API and return its headers
+    /// and (de-chunked) body. Shared by the auto-commit and transactional
+    /// request paths above.
+    fn raw_request(&self, method: &str, path: &str, body: Option<&str>) -> Result<RawResponse> {
+        let body = body.unwrap_or_default();
 
         let http_request = format!(
-            "POST {} HTTP/1.1\r\n\
+            "{} {} HTTP/1.1\r\n\
             Host: {}:{}\r\n\
             Authorization: {}\r\n\
             Content-Type: application/json\r\n\
@@ -171,6 +278,7 @@ impl<T: LinkType> Client<T> {
             Connection: close\r\n\
             \r\n\
             {}",
+            method,
             path,
             self.host,
             self.port,
@@ -191,35 +299,65 @@ impl<T: LinkType> Client<T> {
             .read_to_string(&mut response)
             .map_err(|e| e.to_string())?;
 
+        self.round_trips.fetch_add(1, Ordering::Relaxed);
+        self.bytes_sent
+            .fetch_add(http_request.len() as u64, Ordering::Relaxed);
+        self.bytes_received
+            .fetch_add(response.len() as u64, Ordering::Relaxed);
+
         // Parse HTTP response - find body after empty line
         let body_start = response.find("\r\n\r\n").ok_or("Invalid HTTP response")?;
-        let body = &response[body_start + 4..];
+        let (head, body) = (&response[..body_start], &response[body_start + 4..]);
+
+        let headers = head
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+            .collect();
 
         // Handle chunked encoding if present
-        let json_body = if response.contains("Transfer-Encoding: chunked") {
+        let body = if head.to_lowercase().contains("transfer-encoding: chunked") {
             // Simple chunked decoding - find the JSON object
             if let Some(start) = body.find('{') {
                 if let Some(end) = body.rfind('}') {
-                    &body[start..=end]
+                    body[start..=end].to_string()
                 } else {
-                    body
+                    body.to_string()
                 }
             } else {
-                body
+                body.to_string()
             }
         } else {
-            body
+            body.to_string()
         };
 
-        let cypher_response: CypherResponse = serde_json::from_str(json_body)
-            .map_err(|e| format!("JSON parse error: {} in body: {}", e, json_body))?;
+        Ok(RawResponse { headers, body })
+    }
+}
 
-        if !cypher_response.errors.is_empty() {
-            return Err(cypher_response.errors[0].message.clone().into());
-        }
+/// Headers and body of a raw HTTP response from the Neo4j HTTP API.
+struct RawResponse {
+    headers: HashMap<String, String>,
+    body: String,
+}
 
-        Ok(cypher_response)
+fn statements_body(statements: &[Statement]) -> Result<String> {
+    let request = CypherRequest {
+        statements: statements.to_vec(),
+    };
+    serde_json::to_string(&request).map_err(|e| e.to_string().into())
+}
+
+fn parse_cypher_response(json_body: &str) -> Result<CypherResponse> {
+    let cypher_response: CypherResponse = serde_json::from_str(json_body)
+        .map_err(|e| format!("JSON parse error: {e} in body: {json_body}"))?;
+
+    if !cypher_response.errors.is_empty() {
+        return Err(cypher_response.errors[0].message.clone().into());
     }
+
+    Ok(cypher_response)
 }
 
 fn base64_encode(input: &str) -> String {
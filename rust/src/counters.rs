@@ -0,0 +1,226 @@
+//! # Backend Work-Unit Counters
+//!
+//! Wall-clock time conflates algorithmic work with environmental noise --
+//! especially for Neo4j, where HTTP round-trip latency dominates. This
+//! module collects backend-native work units next to the timings
+//! [`crate::metrics`] already records, so a reader can see *why* one
+//! backend is slower instead of just that it is.
+//!
+//! What's actually counted differs by backend, since each one exposes a
+//! different notion of "work":
+//! - [`crate::Client`] (Neo4j) counts real HTTP round-trips to
+//!   `/db/neo4j/tx*` plus bytes sent/received, via its own atomic counters
+//!   incremented in `raw_request`, and rows returned across every parsed
+//!   response (the legacy transactional API doesn't expose a per-query "db
+//!   hits" count outside of query profiling, so rows returned is the real
+//!   read-volume signal it makes available). [`crate::Transaction`] and
+//!   [`crate::Batched`] wrap a `Client`, so they report its counters too.
+//! - [`crate::Sqlite`] counts SQL statements executed against its
+//!   connection, incremented directly in each [`doublets::Links`] method.
+//! - [`crate::RocksDb`] counts `get`/`put`/`delete`/iterator-scan operations
+//!   against its database, the same way and at the same call sites as
+//!   `Sqlite`'s statement counter.
+//! - The `Doublets_United_*`/`Doublets_Split_*` stores come from the
+//!   external `doublets` crate, whose index-tree internals aren't
+//!   instrumentable from this repository -- there's no field or hook to
+//!   attach a visit counter to. They're intentionally absent from
+//!   [`CountersSink`] rather than approximated with a made-up number.
+
+use std::{alloc::Global, collections::HashMap, env, fs, ops::Sub, sync::Mutex};
+
+use doublets::{
+    data::LinkType,
+    mem::{Alloc, FileMapped},
+    parts::LinkPart,
+    split::{self, DataPart, IndexPart},
+    unit,
+};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::{Batched, Client, Exclusive, RocksDb, Sqlite, Transaction};
+
+/// Global sink benchmark modules record backend-native work units into.
+pub static COUNTERS: Lazy<CountersSink> = Lazy::new(CountersSink::default);
+
+/// Backend-native work performed for one `(backend, operation)` bucket.
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct WorkCounts {
+    /// Network round-trips (Neo4j) or SQL statements executed (SQLite).
+    pub calls: u64,
+    /// Bytes sent over the wire, where applicable (Neo4j only).
+    pub bytes_sent: u64,
+    /// Bytes received over the wire, where applicable (Neo4j only).
+    pub bytes_received: u64,
+    /// Rows returned across every parsed `CypherResponse` (Neo4j only) --
+    /// the real read-volume signal the legacy HTTP API exposes, since it
+    /// doesn't report a per-query "db hits" count outside of profiling.
+    pub rows_returned: u64,
+}
+
+impl Sub for WorkCounts {
+    type Output = WorkCounts;
+
+    /// Delta between two snapshots of the same cumulative counters, e.g.
+    /// `after.work_counts() - before.work_counts()` around a measured
+    /// region.
+    fn sub(self, rhs: Self) -> WorkCounts {
+        WorkCounts {
+            calls: self.calls.saturating_sub(rhs.calls),
+            bytes_sent: self.bytes_sent.saturating_sub(rhs.bytes_sent),
+            bytes_received: self.bytes_received.saturating_sub(rhs.bytes_received),
+            rows_returned: self.rows_returned.saturating_sub(rhs.rows_returned),
+        }
+    }
+}
+
+/// Collects per-`(backend, operation)` work-unit counts across a benchmark
+/// run.
+#[derive(Default)]
+pub struct CountersSink {
+    counts: Mutex<HashMap<(String, String), WorkCounts>>,
+}
+
+impl CountersSink {
+    /// Record `counts` for `(backend, operation)`, overwriting any prior
+    /// entry -- callers snapshot a backend's own cumulative counters before
+    /// and after the measured region and record the delta once per group.
+    pub fn record(&self, backend: &str, operation: &str, counts: WorkCounts) {
+        let mut table = self.counts.lock().expect("counters lock poisoned");
+        table.insert((backend.to_string(), operation.to_string()), counts);
+    }
+
+    /// Every `(backend, operation)` bucket recorded so far, sorted for
+    /// stable output.
+    pub fn report(&self) -> Vec<CounterSummary> {
+        let table = self.counts.lock().expect("counters lock poisoned");
+        let mut report: Vec<_> = table
+            .iter()
+            .map(|((backend, operation), counts)| CounterSummary {
+                backend: backend.clone(),
+                operation: operation.clone(),
+                counts: *counts,
+            })
+            .collect();
+        report.sort_by(|a, b| (&a.backend, &a.operation).cmp(&(&b.backend, &b.operation)));
+        report
+    }
+
+    /// Write the current report to `BENCHMARK_COUNTERS_OUT`, if set. Format
+    /// is inferred from the path's extension, mirroring
+    /// [`crate::metrics::MetricsSink::export`].
+    pub fn export(&self) -> crate::Result<()> {
+        let Ok(path) = env::var("BENCHMARK_COUNTERS_OUT") else {
+            return Ok(());
+        };
+        let report = self.report();
+
+        let contents = if path.ends_with(".json") {
+            serde_json::to_string_pretty(&report)?
+        } else {
+            let mut csv =
+                String::from("backend,operation,calls,bytes_sent,bytes_received,rows_returned\n");
+            for summary in &report {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    summary.backend,
+                    summary.operation,
+                    summary.counts.calls,
+                    summary.counts.bytes_sent,
+                    summary.counts.bytes_received,
+                    summary.counts.rows_returned,
+                ));
+            }
+            csv
+        };
+
+        fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+/// One `(backend, operation)` bucket's work-unit counts.
+#[derive(Serialize)]
+pub struct CounterSummary {
+    pub backend: String,
+    pub operation: String,
+    #[serde(flatten)]
+    pub counts: WorkCounts,
+}
+
+/// Backends that can report their own cumulative [`WorkCounts`] so a
+/// benchmark can snapshot before/after a measured region and record the
+/// delta into [`COUNTERS`], the same way every backend already implements
+/// [`crate::Benched`] so a benchmark can drive its lifecycle generically.
+///
+/// `Doublets_United_*`/`Doublets_Split_*` have no counter to report (see the
+/// module doc comment), so they implement this with the default, which is
+/// always zero.
+pub trait WorkCounted {
+    fn work_counts(&self) -> WorkCounts {
+        WorkCounts::default()
+    }
+}
+
+impl<T: LinkType> WorkCounted for unit::Store<T, FileMapped<LinkPart<T>>> {}
+impl<T: LinkType> WorkCounted for unit::Store<T, Alloc<LinkPart<T>, Global>> {}
+impl<T: LinkType> WorkCounted for split::Store<T, FileMapped<DataPart<T>>, FileMapped<IndexPart<T>>> {}
+impl<T: LinkType> WorkCounted for split::Store<T, Alloc<DataPart<T>, Global>, Alloc<IndexPart<T>, Global>> {}
+
+impl<T: LinkType> WorkCounted for Exclusive<Sqlite<T>> {
+    fn work_counts(&self) -> WorkCounts {
+        WorkCounts {
+            calls: self.get().statements_executed(),
+            bytes_sent: 0,
+            bytes_received: 0,
+            rows_returned: 0,
+        }
+    }
+}
+
+impl<T: LinkType> WorkCounted for Exclusive<RocksDb<T>> {
+    fn work_counts(&self) -> WorkCounts {
+        WorkCounts {
+            calls: self.get().operations(),
+            bytes_sent: 0,
+            bytes_received: 0,
+            rows_returned: 0,
+        }
+    }
+}
+
+impl<T: LinkType> WorkCounted for Exclusive<Client<T>> {
+    fn work_counts(&self) -> WorkCounts {
+        let client = self.get();
+        WorkCounts {
+            calls: client.round_trips(),
+            bytes_sent: client.bytes_sent(),
+            bytes_received: client.bytes_received(),
+            rows_returned: client.rows_returned(),
+        }
+    }
+}
+
+impl<'a, T: LinkType> WorkCounted for Exclusive<Transaction<'a, T>> {
+    fn work_counts(&self) -> WorkCounts {
+        let client = self.get().client();
+        WorkCounts {
+            calls: client.round_trips(),
+            bytes_sent: client.bytes_sent(),
+            bytes_received: client.bytes_received(),
+            rows_returned: client.rows_returned(),
+        }
+    }
+}
+
+impl<'a, T: LinkType> WorkCounted for Exclusive<Batched<'a, T>> {
+    fn work_counts(&self) -> WorkCounts {
+        let client = self.get().client();
+        WorkCounts {
+            calls: client.round_trips(),
+            bytes_sent: client.bytes_sent(),
+            bytes_received: client.bytes_received(),
+            rows_returned: client.rows_returned(),
+        }
+    }
+}
@@ -44,6 +44,11 @@
 //! | `Doublets_Split_NonVolatile`  | File-mapped (split)     | Separate data/index files             |
 //! | `Neo4j_NonTransaction`        | HTTP auto-commit        | Each operation is separate request    |
 //! | `Neo4j_Transaction`           | HTTP auto-commit (same) | Uses transaction wrapper (same impl)  |
+//! | `Neo4j_Batched`               | HTTP auto-commit, batched | Buffers ops, flushes as one `UNWIND` request |
+//! | `Neo4j_Concurrent/{level}`    | HTTP, `level` connections | Bounded job queue feeds `level` worker threads |
+//! | `Sqlite_Volatile`             | In-memory (`:memory:`)  | Relational baseline, single table     |
+//! | `Sqlite_NonVolatile`          | File-backed             | Same schema, persisted to disk        |
+//! | `Doublets_RocksDB`            | Persistent (LSM-tree)   | Embedded KV store, id-ordered keys    |
 //!
 //! ## How the Benchmark Works
 //!
@@ -58,15 +63,26 @@
 
 #[macro_export]
 macro_rules! bench {
-    {|$fork:ident| as $B:ident { $($body:tt)* }} => {
-        (move |bencher: &mut criterion::Bencher, benched: &mut _| {
+    {|$fork:ident| as $B:ident, $op:literal { $($body:tt)* }} => {
+        (move |bencher: &mut criterion::Bencher, benched: &mut _, backend: &str| {
             bencher.iter_custom(|iters| {
                 let mut __bench_duration = Duration::ZERO;
                 macro_rules! elapsed {
                     {$expr:expr} => {{
+                        let __profiler_name = format!("{}/{}", $op, backend);
+                        $crate::profiler::PROFILER
+                            .lock()
+                            .expect("profiler lock poisoned")
+                            .start(&__profiler_name);
                         let __instant = Instant::now();
                         let __ret = {$expr};
-                        __bench_duration += __instant.elapsed();
+                        let __elapsed = __instant.elapsed();
+                        $crate::profiler::PROFILER
+                            .lock()
+                            .expect("profiler lock poisoned")
+                            .stop();
+                        $crate::metrics::METRICS.record(backend, $op, __elapsed);
+                        __bench_duration += __elapsed;
                         __ret
                     }};
                 }
@@ -74,7 +90,7 @@ macro_rules! bench {
                     use linksneo4j::BACKGROUND_LINKS;
                     for _iter in 0..iters {
                         let mut $fork: Fork<$B> = Benched::fork(&mut *benched);
-                        for _ in 0..BACKGROUND_LINKS {
+                        for _ in 0..*BACKGROUND_LINKS {
                             let _ = $fork.create_point()?;
                         }
                         $($body)*
@@ -88,7 +104,8 @@ macro_rules! bench {
 
 use std::{alloc::Global, error, fs::File, io, result};
 
-pub use benched::Benched;
+pub use batched::Batched;
+pub use benched::{Benched, ConcurrentFork};
 pub use client::Client;
 use doublets::{
     data::LinkType,
@@ -98,21 +115,46 @@ use doublets::{
 };
 pub use exclusive::Exclusive;
 pub use fork::Fork;
+pub use rocksdb::RocksDb;
+pub use sqlite::Sqlite;
 pub use transaction::Transaction;
 
+mod batched;
 mod benched;
 mod client;
+pub mod counters;
 pub mod doublets_impl;
 mod exclusive;
 mod fork;
+pub mod load;
+pub mod metrics;
 pub mod neo4j_impl;
+pub mod profiler;
+mod rocksdb;
+pub mod scaling;
+pub mod selector;
+pub mod snapshot;
+mod sqlite;
+pub mod topology;
 mod transaction;
+pub mod workload;
 
 pub type Result<T, E = Box<dyn error::Error + Sync + Send>> = result::Result<T, E>;
 
 /// Number of background links to create before each benchmark iteration.
-/// This simulates a database with existing data.
-pub const BACKGROUND_LINKS: usize = 10;
+/// This simulates a database with existing data. Can be configured via the
+/// `BENCHMARK_BACKGROUND_LINKS` environment variable, same as [`LINK_COUNT`]
+/// is configured via `BENCHMARK_LINK_COUNT` -- the pair of env vars a single
+/// run uses to override the global defaults without recompiling.
+pub fn background_links() -> usize {
+    std::env::var("BENCHMARK_BACKGROUND_LINKS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(10)
+}
+
+/// Lazy static to cache the background-links value.
+pub static BACKGROUND_LINKS: Lazy<usize> = Lazy::new(background_links);
 
 /// Number of links to create/delete/update in each benchmark operation.
 /// Can be configured via BENCHMARK_LINK_COUNT environment variable.
@@ -128,6 +170,68 @@ pub fn link_count() -> usize {
 pub use once_cell::sync::Lazy;
 pub static LINK_COUNT: Lazy<usize> = Lazy::new(link_count);
 
+/// Batch sizes swept by the bulk write benchmarks, each one sent as a single
+/// `UNWIND`-batched Cypher statement on the Neo4j side (and an equivalently
+/// sized loop of `create_point`/`delete` calls on the Doublets side), so the
+/// two backends are compared at identical write granularity.
+/// Configurable via a comma-separated `BENCHMARK_BATCH_SIZES` env var.
+/// Defaults to `[1, 100, 1000, 10000]`.
+pub fn batch_sizes() -> Vec<usize> {
+    std::env::var("BENCHMARK_BATCH_SIZES")
+        .ok()
+        .map(|sizes| {
+            sizes
+                .split(',')
+                .filter_map(|size| size.trim().parse().ok())
+                .collect::<Vec<usize>>()
+        })
+        .filter(|sizes| !sizes.is_empty())
+        .unwrap_or_else(|| vec![1, 100, 1000, 10000])
+}
+
+/// Number of create/update/delete operations the `Neo4j_Batched` backend
+/// ([`Batched`]) buffers before flushing them as a single `UNWIND`-batched
+/// Cypher statement. Configurable via `BENCHMARK_NEO4J_BATCH_SIZE`;
+/// defaults to 100.
+pub fn neo4j_batch_size() -> usize {
+    std::env::var("BENCHMARK_NEO4J_BATCH_SIZE")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(100)
+}
+
+/// Lazy static to cache the Neo4j batch size value.
+pub static NEO4J_BATCH_SIZE: Lazy<usize> = Lazy::new(neo4j_batch_size);
+
+/// Number of worker threads used by the concurrent-throughput benchmarks.
+/// Configurable via the `WORKERS` env var; defaults to 4.
+pub fn worker_count() -> usize {
+    std::env::var("WORKERS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or(4)
+}
+
+/// Worker-pool sizes swept by the `Neo4j_Concurrent` variants of
+/// `create_links`/`delete_links`: each level feeds the same
+/// `create_point`/`delete` workload through a bounded job queue to that
+/// many worker threads, each holding its own `connect()`ed `Client`.
+/// Configurable via a comma-separated `BENCHMARK_CONCURRENCY_LEVELS` env
+/// var. Defaults to `[1, 2, 4, 8]`.
+pub fn concurrency_levels() -> Vec<usize> {
+    std::env::var("BENCHMARK_CONCURRENCY_LEVELS")
+        .ok()
+        .map(|levels| {
+            levels
+                .split(',')
+                .filter_map(|level| level.trim().parse().ok())
+                .collect::<Vec<usize>>()
+        })
+        .filter(|levels| !levels.is_empty())
+        .unwrap_or_else(|| vec![1, 2, 4, 8])
+}
+
 /// Connect to Neo4j database
 pub fn connect<T: LinkType>() -> Result<Client<T>> {
     // Default Neo4j connection parameters
@@ -239,3 +343,96 @@ pub type Neo4jNonTransaction<T = usize> = Exclusive<Client<T>>;
 /// endpoint auto-commits each request. The transaction wrapper exists for API
 /// compatibility and to measure any overhead from the wrapper itself.
 pub type Neo4jTransaction<'a, T = usize> = Exclusive<Transaction<'a, T>>;
+
+/// Neo4j batched wrapper.
+///
+/// Buffers create/update/delete operations and flushes each kind as a
+/// single `UNWIND`-batched Cypher statement once [`NEO4J_BATCH_SIZE`]
+/// operations have accumulated (or on [`Benched::unfork`]), instead of
+/// [`Neo4jNonTransaction`]/[`Neo4jTransaction`]'s one-request-per-link.
+pub type Neo4jBatched<'a, T = usize> = Exclusive<Batched<'a, T>>;
+
+/// SQLite-backed store.
+///
+/// ## Implementation Details
+/// Stores every link as a row in a single `links` table with secondary
+/// indexes on `source` and `target`. Used both volatile (`:memory:`) and
+/// non-volatile (file path) by passing the corresponding path to
+/// [`Sqlite::open`].
+///
+/// ## SQL used for benchmarked operations
+/// ```sql
+/// -- Create point link:
+/// INSERT INTO links (source, target) VALUES (0, 0)
+///
+/// -- Update link:
+/// UPDATE links SET source = ?, target = ? WHERE id = ?
+///
+/// -- Delete link:
+/// DELETE FROM links WHERE id = ?
+///
+/// -- Query by ID (Each Identity):
+/// SELECT id, source, target FROM links WHERE id = ?
+///
+/// -- Query by source (Each Outgoing):
+/// SELECT id, source, target FROM links WHERE source = ?
+///
+/// -- Query by target (Each Incoming):
+/// SELECT id, source, target FROM links WHERE target = ?
+///
+/// -- Query by source AND target (Each Concrete):
+/// SELECT id, source, target FROM links WHERE source = ? AND target = ?
+///
+/// -- Query all (Each All):
+/// SELECT id, source, target FROM links
+/// ```
+pub type SqliteVolatile<T = usize> = Exclusive<Sqlite<T>>;
+
+/// SQLite-backed store, file-backed variant.
+///
+/// Same underlying type as [`SqliteVolatile`]; the difference is purely in
+/// which path is passed to [`Sqlite::open`] (`":memory:"` vs a filesystem
+/// path).
+pub type SqliteNonVolatile<T = usize> = Exclusive<Sqlite<T>>;
+
+/// RocksDB-backed store.
+///
+/// ## Implementation Details
+/// Stores every link across three column families: `links` (`id -> source,
+/// target`) plus `by_source`/`by_target` secondary indexes keyed by
+/// `(source, id)`/`(target, id)` composite big-endian bytes, so outgoing/
+/// incoming/concrete queries become a range scan over an index instead of a
+/// full table scan. Unlike [`SqliteVolatile`]/[`SqliteNonVolatile`], there is
+/// only one variant: RocksDB always persists to `path`, so this is the third
+/// point on the persistence spectrum between the volatile/non-volatile
+/// Doublets stores and the full Neo4j graph server, rather than having its
+/// own volatile/non-volatile split.
+///
+/// ## Operations used for benchmarked operations
+/// ```text
+/// -- Create point link:
+/// put_cf(links, id.to_be_bytes(), 0 ++ 0)
+/// put_cf(by_source, 0 ++ id, id.to_be_bytes())   // source = 0
+/// put_cf(by_target, 0 ++ id, id.to_be_bytes())   // target = 0
+///
+/// -- Update link: remove old index entries, then the same three puts above
+/// -- with the new source/target
+///
+/// -- Delete link: delete_cf on all three column families
+///
+/// -- Query by ID (Each Identity):
+/// get_cf(links, id.to_be_bytes())
+///
+/// -- Query by source (Each Outgoing):
+/// iterator_cf(by_source, From(source ++ 0, Forward)).take_while(prefix == source)
+///
+/// -- Query by target (Each Incoming):
+/// iterator_cf(by_target, From(target ++ 0, Forward)).take_while(prefix == target)
+///
+/// -- Query by source AND target (Each Concrete):
+/// same as Each Outgoing, filtered by target in memory
+///
+/// -- Query all (Each All):
+/// iterator_cf(links, Start)
+/// ```
+pub type DoubletsRocksDb<T = usize> = Exclusive<RocksDb<T>>;
@@ -0,0 +1,346 @@
+//! # Closed-Loop Load Generator
+//!
+//! Criterion's `iter_custom` picks its own sample count and runs batches
+//! back-to-back, which is the right shape for a micro-benchmark but not for
+//! asking "how does this backend behave under a sustained 500 ops/sec for
+//! 30 seconds?" This module drives a backend on a fixed-rate timer schedule
+//! instead: each tick has a scheduled start time computed up front, and a
+//! tick's latency is measured from that scheduled time rather than from
+//! when it actually began -- the coordinated-omission correction. Without
+//! it, a backend that falls behind schedule would only ever report the
+//! latency of the ticks it still managed to start promptly, hiding exactly
+//! the pile-up a load test exists to surface.
+//!
+//! [`LoadConfig::from_env`] reads the target rate and run length so this
+//! mode can be opted into without recompiling; [`run`] drives one backend
+//! through the schedule and returns a [`LoadReport`] with the achieved
+//! rate and a p50/p95/p99 latency breakdown.
+//!
+//! [`run`] is single-threaded, driving one backend handle directly -- the
+//! right shape for the `Neo4j_*` backends, where "load" means one client's
+//! sustained request rate. [`run_concurrent`] answers a different question,
+//! how a backend behaves under *concurrent* access, the way
+//! `benches/benchmarks/concurrent` already does for a fixed op count: it
+//! spreads [`ConcurrentLoadConfig::workers`] threads over the same
+//! fixed-rate schedule (or, with no target rate, lets each worker run
+//! flat-out), against a backend shared behind the caller's own
+//! synchronization the same way the concurrent-throughput benchmark shares
+//! one `Mutex`-guarded store. Its report adds an error count, since
+//! a worker pool hammering a shared, lock-guarded backend is exactly where
+//! operations start failing under contention.
+
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+/// Target rate and run length for one closed-loop load-test pass.
+pub struct LoadConfig {
+    pub target_ops_per_sec: f64,
+    pub duration: Duration,
+}
+
+impl LoadConfig {
+    /// Reads `BENCHMARK_LOAD_TARGET_OPS` and `BENCHMARK_LOAD_DURATION_SECS`.
+    /// Returns `None` if either is unset or unparseable, so callers can
+    /// treat the load-test mode as opt-in and skip it entirely by default.
+    pub fn from_env() -> Option<Self> {
+        let target_ops_per_sec: f64 = env::var("BENCHMARK_LOAD_TARGET_OPS").ok()?.parse().ok()?;
+        let duration_secs: f64 = env::var("BENCHMARK_LOAD_DURATION_SECS")
+            .ok()?
+            .parse()
+            .ok()?;
+        if target_ops_per_sec <= 0.0 || duration_secs <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            target_ops_per_sec,
+            duration: Duration::from_secs_f64(duration_secs),
+        })
+    }
+}
+
+/// Achieved rate and latency distribution for one `(backend, config)` run.
+#[derive(Debug, Clone, Copy)]
+pub struct LoadReport<'a> {
+    pub backend: &'a str,
+    pub requested_ops_per_sec: f64,
+    pub achieved_ops_per_sec: f64,
+    pub completed: usize,
+    /// Ticks that fell more than one whole period behind schedule and were
+    /// skipped rather than run -- the backend couldn't keep up with the
+    /// requested rate.
+    pub missed: usize,
+    /// Operations that completed but reported failure, per the `op`
+    /// closure's return value. Always zero for [`run`], whose `op` doesn't
+    /// report success/failure; populated by [`run_concurrent`].
+    pub errors: usize,
+    pub p50_ns: u128,
+    pub p95_ns: u128,
+    pub p99_ns: u128,
+    pub max_ns: u128,
+}
+
+impl<'a> LoadReport<'a> {
+    fn from_latencies(
+        backend: &'a str,
+        requested_ops_per_sec: f64,
+        elapsed: Duration,
+        mut latencies: Vec<Duration>,
+        missed: usize,
+        errors: usize,
+    ) -> Self {
+        latencies.sort();
+
+        let percentile = |p: f64| -> Duration {
+            if latencies.is_empty() {
+                return Duration::ZERO;
+            }
+            let rank = ((p * (latencies.len() - 1) as f64).round() as usize)
+                .min(latencies.len() - 1);
+            latencies[rank]
+        };
+
+        Self {
+            backend,
+            requested_ops_per_sec,
+            achieved_ops_per_sec: latencies.len() as f64 / elapsed.as_secs_f64(),
+            completed: latencies.len(),
+            missed,
+            errors,
+            p50_ns: percentile(0.50).as_nanos(),
+            p95_ns: percentile(0.95).as_nanos(),
+            p99_ns: percentile(0.99).as_nanos(),
+            max_ns: latencies.last().copied().unwrap_or(Duration::ZERO).as_nanos(),
+        }
+    }
+
+    /// `{backend}: requested=.. achieved=.. p50=.. p95=.. p99=.. max=..
+    /// (completed=.., missed=.., errors=..)`, the register the
+    /// scaling-curve and counters reports already print their summaries in.
+    pub fn print(&self) {
+        println!(
+            "Load[{}]: requested={:.1}/s achieved={:.1}/s p50={:.3}ms p95={:.3}ms p99={:.3}ms max={:.3}ms (completed={}, missed={}, errors={})",
+            self.backend,
+            self.requested_ops_per_sec,
+            self.achieved_ops_per_sec,
+            self.p50_ns as f64 / 1_000_000.0,
+            self.p95_ns as f64 / 1_000_000.0,
+            self.p99_ns as f64 / 1_000_000.0,
+            self.max_ns as f64 / 1_000_000.0,
+            self.completed,
+            self.missed,
+            self.errors,
+        );
+    }
+}
+
+/// Margin left for [`park_until`]'s busy-wait spin after its coarse sleep.
+/// `thread::sleep` is only accurate to the OS scheduler's tick granularity
+/// (commonly ~1ms on Linux), which is too coarse next to a sub-millisecond
+/// tick period at a high target rate; spinning for this last sliver trades
+/// a little CPU for hitting the schedule precisely.
+const SPIN_MARGIN: Duration = Duration::from_micros(200);
+
+/// Parks the current thread until `scheduled`: sleeps for all but the last
+/// [`SPIN_MARGIN`] of the remaining time, then busy-waits the rest.
+fn park_until(scheduled: Instant) {
+    let now = Instant::now();
+    if now >= scheduled {
+        return;
+    }
+    let remaining = scheduled - now;
+    if remaining > SPIN_MARGIN {
+        std::thread::sleep(remaining - SPIN_MARGIN);
+    }
+    while Instant::now() < scheduled {
+        std::hint::spin_loop();
+    }
+}
+
+/// Drives `op` against `backend` on `config`'s fixed-rate schedule for
+/// `config.duration`. Each tick's scheduled start time is `start + i *
+/// period`; if a tick is already overdue when its turn comes, it still
+/// measures latency from its scheduled time (not the actual, delayed start)
+/// so a struggling backend shows inflated tail latency instead of
+/// artificially low latency on the ticks it manages to begin on time. A
+/// tick that falls more than one whole period behind is skipped and counted
+/// as `missed` rather than run -- without this cutoff a sufficiently
+/// overloaded backend would queue ticks forever and the run would never
+/// reach `config.duration` of wall-clock time.
+pub fn run<'a>(
+    backend: &'a str,
+    config: &LoadConfig,
+    mut op: impl FnMut(),
+) -> LoadReport<'a> {
+    let period = Duration::from_secs_f64(1.0 / config.target_ops_per_sec);
+    let run_start = Instant::now();
+
+    let mut latencies = Vec::new();
+    let mut missed = 0usize;
+    let mut tick = 0u32;
+
+    loop {
+        let scheduled = run_start + period * tick;
+        if scheduled >= run_start + config.duration {
+            break;
+        }
+
+        let now = Instant::now();
+        if now > scheduled + period {
+            missed += 1;
+            tick += 1;
+            continue;
+        }
+        park_until(scheduled);
+
+        op();
+        latencies.push(scheduled.elapsed());
+        tick += 1;
+    }
+
+    LoadReport::from_latencies(
+        backend,
+        config.target_ops_per_sec,
+        run_start.elapsed(),
+        latencies,
+        missed,
+        0,
+    )
+}
+
+/// Worker count, run length and optional target rate for the multi-worker
+/// concurrent throughput harness -- distinct from [`LoadConfig`], which
+/// always paces a single client and never reports an error count.
+pub struct ConcurrentLoadConfig {
+    pub workers: usize,
+    pub duration: Duration,
+    /// Per-worker target rate. `None` means each worker runs flat-out,
+    /// mirroring `benches/benchmarks/concurrent`'s unthrottled workers.
+    pub target_ops_per_sec: Option<f64>,
+}
+
+impl ConcurrentLoadConfig {
+    /// Reads `BENCHMARK_DURATION_SECS` (required -- `None` if unset or
+    /// unparseable, so this mode stays opt-in), `BENCHMARK_WORKERS`
+    /// (defaults to [`crate::worker_count`], the same worker count
+    /// `concurrent_throughput` uses) and `BENCHMARK_TARGET_OPS` (optional
+    /// per-worker rate limit).
+    pub fn from_env() -> Option<Self> {
+        let duration_secs: f64 = env::var("BENCHMARK_DURATION_SECS").ok()?.parse().ok()?;
+        if duration_secs <= 0.0 {
+            return None;
+        }
+        let workers = env::var("BENCHMARK_WORKERS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|&n: &usize| n > 0)
+            .unwrap_or_else(crate::worker_count);
+        let target_ops_per_sec = env::var("BENCHMARK_TARGET_OPS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|&rate: &f64| rate > 0.0);
+
+        Some(Self {
+            workers,
+            duration: Duration::from_secs_f64(duration_secs),
+            target_ops_per_sec,
+        })
+    }
+}
+
+/// One worker's share of [`run_concurrent`]: runs `op` for `duration`,
+/// either on the `target_ops_per_sec` fixed-rate schedule [`run`] uses, or
+/// flat-out if no rate was requested. Returns the tick latencies, missed
+/// count and error count this worker alone accumulated.
+fn run_concurrent_worker(
+    duration: Duration,
+    target_ops_per_sec: Option<f64>,
+    op: &(impl Fn() -> bool + Sync),
+) -> (Vec<Duration>, usize, usize) {
+    let run_start = Instant::now();
+    let mut latencies = Vec::new();
+    let mut missed = 0usize;
+    let mut errors = 0usize;
+
+    match target_ops_per_sec {
+        Some(rate) => {
+            let period = Duration::from_secs_f64(1.0 / rate);
+            let mut tick = 0u32;
+            loop {
+                let scheduled = run_start + period * tick;
+                if scheduled >= run_start + duration {
+                    break;
+                }
+
+                let now = Instant::now();
+                if now > scheduled + period {
+                    missed += 1;
+                    tick += 1;
+                    continue;
+                }
+                park_until(scheduled);
+
+                if !op() {
+                    errors += 1;
+                }
+                latencies.push(scheduled.elapsed());
+                tick += 1;
+            }
+        }
+        None => {
+            while run_start.elapsed() < duration {
+                let op_start = Instant::now();
+                if !op() {
+                    errors += 1;
+                }
+                latencies.push(op_start.elapsed());
+            }
+        }
+    }
+
+    (latencies, missed, errors)
+}
+
+/// Spreads `config.workers` threads over `op` for `config.duration`,
+/// optionally rate-limiting each worker to `config.target_ops_per_sec` (see
+/// [`run_concurrent_worker`]), and aggregates every worker's latencies,
+/// missed ticks and errors into one [`LoadReport`]. `op` reports
+/// success/failure via its return value rather than `()`, since a shared
+/// backend under concurrent load is exactly where operations start
+/// failing; callers share their backend the way
+/// `benches/benchmarks/load::doublets`/`neo4j` do (a `Mutex`-guarded
+/// store for Doublets, one `Client` shared across workers for
+/// Neo4j) and have `op` report whether the call it made succeeded.
+pub fn run_concurrent<'a>(
+    backend: &'a str,
+    config: &ConcurrentLoadConfig,
+    op: impl Fn() -> bool + Sync,
+) -> LoadReport<'a> {
+    let run_start = Instant::now();
+    let mut latencies = Vec::new();
+    let mut missed = 0usize;
+    let mut errors = 0usize;
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..config.workers)
+            .map(|_| scope.spawn(|| run_concurrent_worker(config.duration, config.target_ops_per_sec, &op)))
+            .collect();
+        for handle in handles {
+            let (worker_latencies, worker_missed, worker_errors) =
+                handle.join().expect("load worker panicked");
+            latencies.extend(worker_latencies);
+            missed += worker_missed;
+            errors += worker_errors;
+        }
+    });
+
+    LoadReport::from_latencies(
+        backend,
+        config.target_ops_per_sec.unwrap_or(0.0) * config.workers as f64,
+        run_start.elapsed(),
+        latencies,
+        missed,
+        errors,
+    )
+}
@@ -0,0 +1,133 @@
+//! # Latency/Throughput Metrics Export
+//!
+//! Criterion aggregates timing per `bench_function` into a mean/stddev, but
+//! comparing Doublets against Neo4j also needs per-operation latency
+//! *distributions* (their tails diverge far more than their means) in a
+//! machine-readable form. [`METRICS`] is a global sink that the `elapsed!`
+//! macro generated by [`crate::bench`] feeds every measured operation into,
+//! independent of whatever Criterion itself records. At the end of a run,
+//! [`MetricsSink::export`] writes a `(backend, operation)` -> p50/p90/p99/max
+//! + throughput summary to the path named by the `BENCHMARK_METRICS_OUT` env
+//! var (`.json` or `.csv`, inferred from the extension); it is a no-op if
+//! that variable is unset.
+
+use std::{collections::HashMap, env, fs, sync::Mutex, time::Duration};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+/// Global sink every `elapsed!`-measured operation call records into.
+pub static METRICS: Lazy<MetricsSink> = Lazy::new(MetricsSink::default);
+
+/// Collects per-`(backend, operation)` latency samples across a benchmark run.
+#[derive(Default)]
+pub struct MetricsSink {
+    samples: Mutex<HashMap<(String, String), Vec<Duration>>>,
+}
+
+impl MetricsSink {
+    /// Record one measured operation's duration for `(backend, operation)`.
+    pub fn record(&self, backend: &str, operation: &str, duration: Duration) {
+        let mut samples = self.samples.lock().expect("metrics lock poisoned");
+        samples
+            .entry((backend.to_string(), operation.to_string()))
+            .or_default()
+            .push(duration);
+    }
+
+    /// Summarize every `(backend, operation)` bucket recorded so far, sorted
+    /// for stable output.
+    pub fn report(&self) -> Vec<OperationSummary> {
+        let samples = self.samples.lock().expect("metrics lock poisoned");
+        let mut report: Vec<_> = samples
+            .iter()
+            .map(|((backend, operation), durations)| {
+                OperationSummary::from_samples(backend, operation, durations)
+            })
+            .collect();
+        report.sort_by(|a, b| (&a.backend, &a.operation).cmp(&(&b.backend, &b.operation)));
+        report
+    }
+
+    /// Write the current report to `BENCHMARK_METRICS_OUT`, if set. Format
+    /// is inferred from the path's extension (`.json` or anything else,
+    /// which defaults to CSV). Never fails the benchmark run itself; the
+    /// caller decides how to surface an `Err`.
+    pub fn export(&self) -> crate::Result<()> {
+        let Ok(path) = env::var("BENCHMARK_METRICS_OUT") else {
+            return Ok(());
+        };
+        let report = self.report();
+
+        let contents = if path.ends_with(".json") {
+            serde_json::to_string_pretty(&report)?
+        } else {
+            let mut csv =
+                String::from("backend,operation,count,p50_ns,p90_ns,p99_ns,max_ns,ops_per_sec\n");
+            for summary in &report {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{},{:.2}\n",
+                    summary.backend,
+                    summary.operation,
+                    summary.count,
+                    summary.p50_ns,
+                    summary.p90_ns,
+                    summary.p99_ns,
+                    summary.max_ns,
+                    summary.ops_per_sec,
+                ));
+            }
+            csv
+        };
+
+        fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+/// p50/p90/p99/max latency (in nanoseconds) plus aggregate throughput for one
+/// `(backend, operation)` bucket.
+#[derive(Serialize)]
+pub struct OperationSummary {
+    pub backend: String,
+    pub operation: String,
+    pub count: usize,
+    pub p50_ns: u128,
+    pub p90_ns: u128,
+    pub p99_ns: u128,
+    pub max_ns: u128,
+    pub ops_per_sec: f64,
+}
+
+impl OperationSummary {
+    fn from_samples(backend: &str, operation: &str, durations: &[Duration]) -> Self {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+
+        let percentile = |p: f64| -> Duration {
+            if sorted.is_empty() {
+                return Duration::ZERO;
+            }
+            let rank = ((p * (sorted.len() - 1) as f64).round() as usize).min(sorted.len() - 1);
+            sorted[rank]
+        };
+
+        let total: Duration = sorted.iter().sum();
+        let ops_per_sec = if total.as_secs_f64() > 0.0 {
+            sorted.len() as f64 / total.as_secs_f64()
+        } else {
+            0.0
+        };
+
+        Self {
+            backend: backend.to_string(),
+            operation: operation.to_string(),
+            count: sorted.len(),
+            p50_ns: percentile(0.50).as_nanos(),
+            p90_ns: percentile(0.90).as_nanos(),
+            p99_ns: percentile(0.99).as_nanos(),
+            max_ns: sorted.last().copied().unwrap_or(Duration::ZERO).as_nanos(),
+            ops_per_sec,
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! # Pluggable Profiler Hooks
+//!
+//! [`crate::metrics`] and [`crate::counters`] answer "how long did this
+//! take" and "how much backend-native work did it do" -- neither answers
+//! *why* the time or work landed where it did. This module hooks a
+//! [`Profiler`] around the exact same window the `bench!` macro's
+//! `elapsed!` block already measures, so a profiler sees the same
+//! `(backend, operation)` boundary `metrics`/`counters` do.
+//!
+//! [`PROFILER`] is selected once, from `BENCHMARK_PROFILER`:
+//! - `cpu` -- [`CpuProfiler`] samples `/proc/self/stat`'s minor/major
+//!   page-fault counters across the window and logs the delta, the signal
+//!   that tells apart a backend paging its memory-mapped files in from one
+//!   that's genuinely CPU-bound.
+//! - `sysmon` -- [`SysMonProfiler`] polls `/proc/self/status`'s `VmRSS` on
+//!   a background thread for the window's peak, and reads `/proc/self/stat`
+//!   user/sys CPU ticks at each end, the signal that tells apart a backend
+//!   burning CPU from one mostly waiting on the network (e.g.
+//!   `Neo4j_Transaction` between round-trips).
+//! - unset or `none` -- [`NoopProfiler`], so every other benchmark run
+//!   pays nothing for this hook.
+//!
+//! A real stack-sampling profiler (what `windsock` gets from `samply`)
+//! needs either an external crate or platform-specific unwinding code;
+//! neither is available here (this crate has no dependency manifest to add
+//! one to), so `cpu` substitutes a page-fault delta sampler -- it answers
+//! the same "is this memory-bound or CPU-bound" question `samply`'s flame
+//! graphs would, just without the graph. A third kind `windsock` offers,
+//! scraping a server's own exported counters, is already covered by
+//! [`crate::counters`] recording `Client::round_trips`/bytes/rows once per
+//! `(backend, operation)` group rather than as a `Profiler` -- see that
+//! module's doc for why.
+//!
+//! Each profiler's `name` is `"{operation}/{backend}"` (e.g.
+//! `Each_Outgoing/Doublets_Split_NonVolatile`), and in addition to its
+//! one-line summary on stdout, [`stop`][Profiler::stop] writes that same
+//! summary as a JSON sidecar file under `BENCHMARK_PROFILER_OUT_DIR`, if
+//! set -- `{dir}/{operation}_{backend}.{kind}.json`, `/` replaced with `_`
+//! since it isn't a valid path separator component on its own here. A
+//! Criterion HTML report or a metrics export already groups by the same
+//! id; the sidecar lets an external tool line a profiler's output up with
+//! either one.
+
+use std::{
+    env, fs,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+
+/// `/proc`'s clock-tick rate used to convert `utime`/`stime` into
+/// milliseconds; 100 Hz on every Linux platform this crate targets
+/// (`sysconf(_SC_CLK_TCK)`'s near-universal value).
+const CLOCK_TICKS_PER_SEC: u64 = 100;
+
+/// Hooked by the `bench!` macro's `elapsed!` block around each measured
+/// region: `start(name)` right before the timer begins, `stop()` right
+/// after it ends, `name` being `"{operation}/{backend}"` -- the same
+/// `(backend, operation)` pair `metrics::METRICS`/`counters::COUNTERS`
+/// record under, just backend-last to match how this crate already names
+/// a `(benchmark, backend)` cell elsewhere (e.g.
+/// `Each_Outgoing/Doublets_Split_NonVolatile`).
+pub trait Profiler: Send {
+    fn start(&mut self, name: &str);
+    fn stop(&mut self);
+}
+
+/// The default: does nothing. Selected when `BENCHMARK_PROFILER` is unset
+/// or `none`.
+#[derive(Default)]
+pub struct NoopProfiler;
+
+impl Profiler for NoopProfiler {
+    fn start(&mut self, _name: &str) {}
+    fn stop(&mut self) {}
+}
+
+/// Cumulative counters `/proc/self/stat` reports; a snapshot taken at
+/// `start` and again at `stop` turns into the window's delta.
+struct ProcStat {
+    minor_faults: u64,
+    major_faults: u64,
+    utime_ticks: u64,
+    stime_ticks: u64,
+}
+
+/// Parses `/proc/self/stat`'s whitespace-separated fields, skipping past
+/// the `comm` field (field 2), which is parenthesized and may itself
+/// contain spaces. Returns `None` (rather than panicking) on any
+/// non-Linux platform or sandboxed environment without `/proc` -- a
+/// profiler that can't read its signal should fall silent, not fail the
+/// benchmark it's attached to.
+fn read_proc_stat() -> Option<ProcStat> {
+    let contents = fs::read_to_string("/proc/self/stat").ok()?;
+    let after_comm = contents.rsplit_once(')')?.1;
+    let fields: Vec<&str> = after_comm.split_whitespace().collect();
+    // Fields after `comm` are 0-indexed here but 3-indexed in `proc(5)`;
+    // minflt is field 10, majflt field 12, utime field 14, stime field 15.
+    Some(ProcStat {
+        minor_faults: fields.get(7)?.parse().ok()?,
+        major_faults: fields.get(9)?.parse().ok()?,
+        utime_ticks: fields.get(11)?.parse().ok()?,
+        stime_ticks: fields.get(12)?.parse().ok()?,
+    })
+}
+
+/// Current resident set size in KiB, from `/proc/self/status`'s `VmRSS`
+/// line. `None` under the same conditions as [`read_proc_stat`].
+fn read_rss_kb() -> Option<u64> {
+    let contents = fs::read_to_string("/proc/self/status").ok()?;
+    contents.lines().find_map(|line| {
+        line.strip_prefix("VmRSS:")
+            .and_then(|rest| rest.trim().trim_end_matches("kB").trim().parse().ok())
+    })
+}
+
+/// Writes `value` as a JSON sidecar for `name` under `BENCHMARK_PROFILER_OUT_DIR`,
+/// if that env var is set; a no-op otherwise, the same opt-in-by-env-var
+/// shape [`crate::metrics::MetricsSink::export`] uses for its own output
+/// file. `/` in `name` (the `{operation}/{backend}` id) isn't a valid path
+/// component on its own, so it's replaced with `_` in the file name.
+fn write_sidecar(name: &str, kind: &str, value: Value) {
+    let Ok(dir) = env::var("BENCHMARK_PROFILER_OUT_DIR") else {
+        return;
+    };
+    let sanitized = name.replace('/', "_");
+    let path = format!("{dir}/{sanitized}.{kind}.json");
+    if let Ok(contents) = serde_json::to_string_pretty(&value) {
+        let _ = fs::write(path, contents);
+    }
+}
+
+/// Samples page-fault counters across the measured window and logs the
+/// delta on [`Profiler::stop`]. A high major-fault count on a
+/// memory-mapped backend (`Doublets_*_NonVolatile`) points at page-cache
+/// misses, not the cost of its index-tree algorithm; a near-zero count on
+/// an HTTP backend (`Neo4j_*`) rules memory pressure out entirely.
+#[derive(Default)]
+pub struct CpuProfiler {
+    name: String,
+    before: Option<ProcStat>,
+}
+
+impl Profiler for CpuProfiler {
+    fn start(&mut self, name: &str) {
+        self.name = name.to_string();
+        self.before = read_proc_stat();
+    }
+
+    fn stop(&mut self) {
+        let (Some(before), Some(after)) = (self.before.take(), read_proc_stat()) else {
+            return;
+        };
+        let minor_faults = after.minor_faults.saturating_sub(before.minor_faults);
+        let major_faults = after.major_faults.saturating_sub(before.major_faults);
+        println!(
+            "profiler[cpu] {}: minor_faults={minor_faults} major_faults={major_faults}",
+            self.name,
+        );
+        write_sidecar(
+            &self.name,
+            "cpu",
+            json!({ "minor_faults": minor_faults, "major_faults": major_faults }),
+        );
+    }
+}
+
+/// Polls `/proc/self/status`'s `VmRSS` on a background thread for the
+/// window's peak, and reads `/proc/self/stat`'s user/sys CPU ticks at each
+/// end; logs both on [`Profiler::stop`]. Low CPU time next to a long wall
+///-clock window is the signature of a network-bound backend (e.g.
+/// `Neo4j_Transaction` idling between round-trips) rather than a slow one.
+#[derive(Default)]
+pub struct SysMonProfiler {
+    name: String,
+    before: Option<ProcStat>,
+    peak_rss_kb: Arc<AtomicU64>,
+    stop_poll: Option<Arc<AtomicBool>>,
+    poll_thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Profiler for SysMonProfiler {
+    fn start(&mut self, name: &str) {
+        self.name = name.to_string();
+        self.before = read_proc_stat();
+        self.peak_rss_kb = Arc::new(AtomicU64::new(read_rss_kb().unwrap_or(0)));
+        let stop_poll = Arc::new(AtomicBool::new(false));
+        let peak_rss_kb = Arc::clone(&self.peak_rss_kb);
+        let poll_stop_flag = Arc::clone(&stop_poll);
+        self.poll_thread = Some(thread::spawn(move || {
+            while !poll_stop_flag.load(Ordering::Relaxed) {
+                if let Some(rss_kb) = read_rss_kb() {
+                    peak_rss_kb.fetch_max(rss_kb, Ordering::Relaxed);
+                }
+                thread::sleep(Duration::from_millis(5));
+            }
+        }));
+        self.stop_poll = Some(stop_poll);
+    }
+
+    fn stop(&mut self) {
+        if let Some(stop_poll) = self.stop_poll.take() {
+            stop_poll.store(true, Ordering::Relaxed);
+        }
+        if let Some(poll_thread) = self.poll_thread.take() {
+            let _ = poll_thread.join();
+        }
+        let Some(before) = self.before.take() else {
+            return;
+        };
+        let Some(after) = read_proc_stat() else {
+            return;
+        };
+        let user_ms =
+            after.utime_ticks.saturating_sub(before.utime_ticks) * 1_000 / CLOCK_TICKS_PER_SEC;
+        let sys_ms =
+            after.stime_ticks.saturating_sub(before.stime_ticks) * 1_000 / CLOCK_TICKS_PER_SEC;
+        let peak_rss_kb = self.peak_rss_kb.load(Ordering::Relaxed);
+        println!(
+            "profiler[sysmon] {}: peak_rss_kb={peak_rss_kb} user_ms={user_ms} sys_ms={sys_ms}",
+            self.name,
+        );
+        write_sidecar(
+            &self.name,
+            "sysmon",
+            json!({ "peak_rss_kb": peak_rss_kb, "user_ms": user_ms, "sys_ms": sys_ms }),
+        );
+    }
+}
+
+/// Global profiler every `elapsed!`-measured operation starts and stops
+/// around, selected once from `BENCHMARK_PROFILER` the same way
+/// [`crate::selector::SELECTOR`] parses its own env var once at first use.
+pub static PROFILER: Lazy<Mutex<Box<dyn Profiler>>> = Lazy::new(|| Mutex::new(select_profiler()));
+
+fn select_profiler() -> Box<dyn Profiler> {
+    match env::var("BENCHMARK_PROFILER").as_deref() {
+        Ok("cpu") => Box::new(CpuProfiler::default()),
+        Ok("sysmon") => Box::new(SysMonProfiler::default()),
+        _ => Box::new(NoopProfiler),
+    }
+}
@@ -0,0 +1,357 @@
+use std::{
+    marker::PhantomData,
+    sync::atomic::{AtomicI64, AtomicU64, Ordering},
+};
+
+use doublets::{
+    data::{Error, Flow, LinkType, LinksConstants, ReadHandler, WriteHandler},
+    Doublets, Link, Links,
+};
+use rocksdb::{ColumnFamilyDescriptor, Direction, IteratorMode, Options, DB};
+
+use crate::{Exclusive, Result};
+
+const LINKS_CF: &str = "links";
+const BY_SOURCE_CF: &str = "by_source";
+const BY_TARGET_CF: &str = "by_target";
+
+/// RocksDB-backed links store, across three column families:
+///
+/// - `links`: `id.to_be_bytes() -> source.to_be_bytes() ++ target.to_be_bytes()`,
+///   the primary store; `id` is the point-lookup key for `each_identity`.
+/// - `by_source`: `source.to_be_bytes() ++ id.to_be_bytes() -> target.to_be_bytes()`,
+///   a secondary index letting `each_outgoing`/`each_concrete` range-scan by
+///   source prefix instead of a full table scan.
+/// - `by_target`: `target.to_be_bytes() ++ id.to_be_bytes() -> source.to_be_bytes()`,
+///   the mirror index for `each_incoming`.
+///
+/// Every write updates all three column families together, same as an SQL
+/// `UPDATE`/`DELETE` on `links` implicitly maintains `Sqlite`'s `links_source`/
+/// `links_target` indexes.
+///
+/// `id` allocation mirrors the `MATCH ... max(l.id)` startup scan
+/// `Client::new` does for Neo4j: [`RocksDb::open`] seeks to the last key of
+/// `links` via a reverse iterator once, at open time, rather than
+/// maintaining a separate counter key that every write would also have to
+/// touch.
+pub struct RocksDb<T: LinkType> {
+    db: DB,
+    constants: LinksConstants<T>,
+    next_id: AtomicI64,
+    operations: AtomicU64,
+    _marker: PhantomData<T>,
+}
+
+fn encode_id(id: i64) -> [u8; 8] {
+    id.to_be_bytes()
+}
+
+fn encode_value(source: i64, target: i64) -> [u8; 16] {
+    let mut value = [0u8; 16];
+    value[..8].copy_from_slice(&source.to_be_bytes());
+    value[8..].copy_from_slice(&target.to_be_bytes());
+    value
+}
+
+fn decode_id(key: &[u8]) -> i64 {
+    i64::from_be_bytes(key[..8].try_into().unwrap_or([0; 8]))
+}
+
+fn decode_value(value: &[u8]) -> (i64, i64) {
+    let source = i64::from_be_bytes(value[..8].try_into().unwrap_or([0; 8]));
+    let target = i64::from_be_bytes(value[8..].try_into().unwrap_or([0; 8]));
+    (source, target)
+}
+
+/// Composite `prefix.to_be_bytes() ++ id.to_be_bytes()` key for the
+/// `by_source`/`by_target` column families.
+fn composite_key(prefix: i64, id: i64) -> [u8; 16] {
+    let mut key = [0u8; 16];
+    key[..8].copy_from_slice(&prefix.to_be_bytes());
+    key[8..].copy_from_slice(&id.to_be_bytes());
+    key
+}
+
+fn decode_other(value: &[u8]) -> i64 {
+    i64::from_be_bytes(value[..8].try_into().unwrap_or([0; 8]))
+}
+
+impl<T: LinkType> RocksDb<T> {
+    /// Open (or create) a RocksDB database at `path`, creating the `links`,
+    /// `by_source` and `by_target` column families if they don't already
+    /// exist.
+    pub fn open(path: &str) -> Result<Self> {
+        let mut db_options = Options::default();
+        db_options.create_if_missing(true);
+        db_options.create_missing_column_families(true);
+
+        let cf_options = Options::default();
+        let column_families = [LINKS_CF, BY_SOURCE_CF, BY_TARGET_CF]
+            .into_iter()
+            .map(|name| ColumnFamilyDescriptor::new(name, cf_options.clone()));
+        let db = DB::open_cf_descriptors(&db_options, path, column_families)
+            .map_err(|e| e.to_string())?;
+
+        let links = db.cf_handle(LINKS_CF).expect("links column family");
+        let next_id = db
+            .iterator_cf(links, IteratorMode::End)
+            .next()
+            .and_then(|entry| entry.ok())
+            .map(|(key, _)| decode_id(&key) + 1)
+            .unwrap_or(1);
+
+        Ok(Self {
+            db,
+            constants: LinksConstants::new(),
+            next_id: AtomicI64::new(next_id),
+            operations: AtomicU64::new(0),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Number of RocksDB operations (`get`/`put`/`delete`/iterator scan)
+    /// performed by the [`Links`] methods below, so far. Counts one per
+    /// call, matching how [`Sqlite::statements_executed`] counts one SQL
+    /// statement per call.
+    pub fn operations(&self) -> u64 {
+        self.operations.load(Ordering::Relaxed)
+    }
+
+    /// Delete every key in all three column families and reset the id
+    /// counter, leaving the database empty for the next benchmark iteration
+    /// -- the RocksDB equivalent of `Sqlite`'s `DELETE FROM links` teardown.
+    pub fn clear(&mut self) -> Result<()> {
+        for name in [LINKS_CF, BY_SOURCE_CF, BY_TARGET_CF] {
+            let cf = self.db.cf_handle(name).expect("column family exists");
+            let keys: Vec<_> = self
+                .db
+                .iterator_cf(cf, IteratorMode::Start)
+                .filter_map(|entry| entry.ok())
+                .map(|(key, _)| key)
+                .collect();
+            for key in keys {
+                self.db.delete_cf(cf, key).map_err(|e| e.to_string())?;
+            }
+        }
+        self.next_id.store(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Point lookup of `(source, target)` by `id` in the `links` column
+    /// family.
+    fn get_by_id(&self, id: i64) -> Option<(i64, i64)> {
+        let links = self.db.cf_handle(LINKS_CF).expect("links column family");
+        self.db
+            .get_cf(links, encode_id(id))
+            .ok()
+            .flatten()
+            .map(|value| decode_value(&value))
+    }
+
+    /// Range-scans `by_source`/`by_target` for every `(id, target)`/`(id,
+    /// source)` pair under `prefix`, used by `each_outgoing`/`each_incoming`
+    /// instead of a full `links` scan.
+    fn scan_index(&self, cf_name: &str, prefix: i64) -> Vec<(i64, i64)> {
+        let cf = self.db.cf_handle(cf_name).expect("column family exists");
+        self.db
+            .iterator_cf(
+                cf,
+                IteratorMode::From(&composite_key(prefix, 0), Direction::Forward),
+            )
+            .filter_map(|entry| entry.ok())
+            .take_while(|(key, _)| key.len() >= 8 && decode_id(&key[..8]) == prefix)
+            .map(|(key, value)| (decode_id(&key[8..]), decode_other(&value)))
+            .collect()
+    }
+
+    /// Rows matching a `[id, source, target]`-shaped query, picking the
+    /// cheapest available path: a point `get` when `id` is constrained, an
+    /// index range-scan when `source` or `target` is constrained (plus an
+    /// in-memory filter if the other one is too), and a full `links` scan
+    /// only when nothing is constrained (`each_all`).
+    fn matching(&self, query: &[T], any: T) -> Vec<(i64, i64, i64)> {
+        if !query.is_empty() && query[0] != any {
+            let id = query[0].as_i64();
+            return self
+                .get_by_id(id)
+                .into_iter()
+                .map(|(source, target)| (id, source, target))
+                .collect();
+        }
+
+        let (source_filter, target_filter) = if query.len() == 3 {
+            (
+                (query[1] != any).then(|| query[1].as_i64()),
+                (query[2] != any).then(|| query[2].as_i64()),
+            )
+        } else {
+            (None, None)
+        };
+
+        match (source_filter, target_filter) {
+            (Some(source), target_filter) => self
+                .scan_index(BY_SOURCE_CF, source)
+                .into_iter()
+                .filter(|&(_, target)| target_filter.map_or(true, |t| t == target))
+                .map(|(id, target)| (id, source, target))
+                .collect(),
+            (None, Some(target)) => self
+                .scan_index(BY_TARGET_CF, target)
+                .into_iter()
+                .map(|(id, source)| (id, source, target))
+                .collect(),
+            (None, None) => {
+                let links = self.db.cf_handle(LINKS_CF).expect("links column family");
+                self.db
+                    .iterator_cf(links, IteratorMode::Start)
+                    .filter_map(|entry| entry.ok())
+                    .map(|(key, value)| {
+                        let (source, target) = decode_value(&value);
+                        (decode_id(&key), source, target)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn put_link(&self, id: i64, source: i64, target: i64) {
+        let links = self.db.cf_handle(LINKS_CF).expect("links column family");
+        let by_source = self.db.cf_handle(BY_SOURCE_CF).expect("by_source column family");
+        let by_target = self.db.cf_handle(BY_TARGET_CF).expect("by_target column family");
+
+        let _ = self.db.put_cf(links, encode_id(id), encode_value(source, target));
+        let _ = self
+            .db
+            .put_cf(by_source, composite_key(source, id), target.to_be_bytes());
+        let _ = self
+            .db
+            .put_cf(by_target, composite_key(target, id), source.to_be_bytes());
+    }
+
+    fn remove_link(&self, id: i64, source: i64, target: i64) {
+        let links = self.db.cf_handle(LINKS_CF).expect("links column family");
+        let by_source = self.db.cf_handle(BY_SOURCE_CF).expect("by_source column family");
+        let by_target = self.db.cf_handle(BY_TARGET_CF).expect("by_target column family");
+
+        let _ = self.db.delete_cf(links, encode_id(id));
+        let _ = self.db.delete_cf(by_source, composite_key(source, id));
+        let _ = self.db.delete_cf(by_target, composite_key(target, id));
+    }
+}
+
+impl<T: LinkType> Links<T> for Exclusive<RocksDb<T>> {
+    fn constants(&self) -> &LinksConstants<T> {
+        &self.constants
+    }
+
+    fn count_links(&self, query: &[T]) -> T {
+        let any = self.constants.any;
+        self.get().operations.fetch_add(1, Ordering::Relaxed);
+        self.get()
+            .matching(query, any)
+            .len()
+            .try_into()
+            .ok()
+            .unwrap_or(T::ZERO)
+    }
+
+    fn create_links(
+        &mut self,
+        _query: &[T],
+        handler: WriteHandler<T>,
+    ) -> std::result::Result<Flow, Error<T>> {
+        self.operations.fetch_add(1, Ordering::Relaxed);
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.put_link(id, 0, 0);
+
+        Ok(handler(
+            Link::nothing(),
+            Link::new(id.try_into().unwrap_or(T::ZERO), T::ZERO, T::ZERO),
+        ))
+    }
+
+    fn each_links(&self, query: &[T], handler: ReadHandler<T>) -> Flow {
+        let any = self.constants.any;
+        self.get().operations.fetch_add(1, Ordering::Relaxed);
+
+        for (id, source, target) in self.get().matching(query, any) {
+            if let Flow::Break = handler(Link::new(
+                id.try_into().unwrap_or(T::ZERO),
+                source.try_into().unwrap_or(T::ZERO),
+                target.try_into().unwrap_or(T::ZERO),
+            )) {
+                return Flow::Break;
+            }
+        }
+
+        Flow::Continue
+    }
+
+    fn update_links(
+        &mut self,
+        query: &[T],
+        change: &[T],
+        handler: WriteHandler<T>,
+    ) -> std::result::Result<Flow, Error<T>> {
+        let id = query[0];
+        let source = change[1];
+        let target = change[2];
+
+        self.operations.fetch_add(1, Ordering::Relaxed);
+
+        let (old_source, old_target) = self.get_by_id(id.as_i64()).unwrap_or((0, 0));
+        self.remove_link(id.as_i64(), old_source, old_target);
+        self.put_link(id.as_i64(), source.as_i64(), target.as_i64());
+
+        Ok(handler(
+            Link::new(
+                id,
+                old_source.try_into().unwrap_or(T::ZERO),
+                old_target.try_into().unwrap_or(T::ZERO),
+            ),
+            Link::new(id, source, target),
+        ))
+    }
+
+    fn delete_links(
+        &mut self,
+        query: &[T],
+        handler: WriteHandler<T>,
+    ) -> std::result::Result<Flow, Error<T>> {
+        let id = query[0];
+
+        self.operations.fetch_add(1, Ordering::Relaxed);
+
+        match self.get_by_id(id.as_i64()) {
+            Some((source, target)) => {
+                self.remove_link(id.as_i64(), source, target);
+                Ok(handler(
+                    Link::new(
+                        id,
+                        source.try_into().unwrap_or(T::ZERO),
+                        target.try_into().unwrap_or(T::ZERO),
+                    ),
+                    Link::nothing(),
+                ))
+            }
+            None => Err(Error::<T>::NotExists(id)),
+        }
+    }
+}
+
+impl<T: LinkType> Doublets<T> for Exclusive<RocksDb<T>> {
+    fn get_link(&self, index: T) -> Option<Link<T>> {
+        self.get().operations.fetch_add(1, Ordering::Relaxed);
+
+        self.get()
+            .get_by_id(index.as_i64())
+            .map(|(source, target)| {
+                Link::new(
+                    index,
+                    source.try_into().unwrap_or(T::ZERO),
+                    target.try_into().unwrap_or(T::ZERO),
+                )
+            })
+    }
+}
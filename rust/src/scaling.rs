@@ -0,0 +1,114 @@
+//! # Scaling-Curve Regression
+//!
+//! A single hardcoded [`crate::BACKGROUND_LINKS`] hides how a backend scales:
+//! the same number lumps a backend's fixed per-request overhead (HTTP
+//! round-trips for the `Neo4j_*` backends) in with its marginal per-link
+//! cost. This module re-runs a benchmarked operation across a swept range of
+//! database sizes and fits `time = intercept + slope * size` by ordinary
+//! least squares, so the two costs can be read off separately instead of
+//! guessed at from one data point.
+
+use std::env;
+
+/// Database sizes swept by the scaling-curve benchmarks. Configurable via a
+/// comma-separated `BENCHMARK_SCALING_SIZES` env var.
+/// Defaults to `[1000, 10000, 100000, 1000000]`.
+pub fn scaling_sizes() -> Vec<usize> {
+    env::var("BENCHMARK_SCALING_SIZES")
+        .ok()
+        .map(|sizes| {
+            sizes
+                .split(',')
+                .filter_map(|size| size.trim().parse().ok())
+                .collect::<Vec<usize>>()
+        })
+        .filter(|sizes| !sizes.is_empty())
+        .unwrap_or_else(|| vec![1_000, 10_000, 100_000, 1_000_000])
+}
+
+/// A linear model `time = intercept + slope * size` fit to `(size, time)`
+/// samples by ordinary least squares.
+#[derive(Debug, Clone, Copy)]
+pub struct LinearFit {
+    /// Fixed overhead independent of database size (the `a` term).
+    pub intercept: f64,
+    /// Marginal per-link cost (the `b` term).
+    pub slope: f64,
+    /// Coefficient of determination of the fit against `samples`, in
+    /// `[0, 1]` for a sane fit (it can go negative if the model is worse
+    /// than just predicting the mean). Low values mean the per-link cost
+    /// isn't actually linear in size over the sampled range.
+    pub r_squared: f64,
+}
+
+impl LinearFit {
+    /// Fits `samples` (each an `(size, time)` pair) by ordinary least
+    /// squares:
+    ///
+    /// ```text
+    /// b = (n*Σxy - Σx*Σy) / (n*Σx² - (Σx)²)
+    /// a = (Σy - b*Σx) / n
+    /// r² = 1 - Σ(y - (a + b*x))² / Σ(y - ȳ)²
+    /// ```
+    ///
+    /// Returns an error only if `samples` is empty -- there's no time to
+    /// report at all. With fewer than two distinct sizes present (a single
+    /// measurement point, or several repeats of the same size),
+    /// `n*Σx² - (Σx)²` is zero and a slope isn't identifiable from a flat
+    /// `x`, so the fit falls back to reporting the mean measured time as
+    /// `intercept` with `slope = 0` instead of dividing by that zero.
+    pub fn fit(samples: &[(f64, f64)]) -> crate::Result<Self> {
+        if samples.is_empty() {
+            return Err("need at least one sample to fit a line, got none".into());
+        }
+
+        let distinct = samples
+            .iter()
+            .map(|&(size, _)| size.to_bits())
+            .collect::<std::collections::HashSet<_>>();
+        if distinct.len() < 2 {
+            let mean_y = samples.iter().map(|&(_, y)| y).sum::<f64>() / samples.len() as f64;
+            let ss_tot: f64 = samples.iter().map(|&(_, y)| (y - mean_y).powi(2)).sum();
+            return Ok(Self {
+                intercept: mean_y,
+                slope: 0.0,
+                r_squared: if ss_tot == 0.0 { 1.0 } else { 0.0 },
+            });
+        }
+
+        let n = samples.len() as f64;
+        let sum_x: f64 = samples.iter().map(|&(x, _)| x).sum();
+        let sum_y: f64 = samples.iter().map(|&(_, y)| y).sum();
+        let sum_xy: f64 = samples.iter().map(|&(x, y)| x * y).sum();
+        let sum_xx: f64 = samples.iter().map(|&(x, _)| x * x).sum();
+
+        let slope = (n * sum_xy - sum_x * sum_y) / (n * sum_xx - sum_x * sum_x);
+        let intercept = (sum_y - slope * sum_x) / n;
+
+        let mean_y = sum_y / n;
+        let ss_tot: f64 = samples.iter().map(|&(_, y)| (y - mean_y).powi(2)).sum();
+        let ss_res: f64 = samples
+            .iter()
+            .map(|&(x, y)| (y - (intercept + slope * x)).powi(2))
+            .sum();
+        let r_squared = if ss_tot == 0.0 { 1.0 } else { 1.0 - ss_res / ss_tot };
+
+        Ok(Self {
+            intercept,
+            slope,
+            r_squared,
+        })
+    }
+}
+
+/// The median of a set of samples (sorted ascending); used to summarize the
+/// repeated timings collected at each swept size before fitting.
+pub fn median(samples: &mut [f64]) -> f64 {
+    samples.sort_by(|a, b| a.partial_cmp(b).expect("NaN duration"));
+    let mid = samples.len() / 2;
+    if samples.len() % 2 == 0 {
+        (samples[mid - 1] + samples[mid]) / 2.0
+    } else {
+        samples[mid]
+    }
+}
@@ -0,0 +1,103 @@
+//! # Benchmark Selection
+//!
+//! Every benchmark `pub fn` hardcodes its own backend list and runs the full
+//! matrix every time, which makes bisecting a single cell (one backend, one
+//! operation, one size) during an investigation slow, and leaves CI with no
+//! way to pin exactly which cells it tracks without editing source.
+//!
+//! [`Selector`] parses `BENCHMARK_SELECT`, a comma-separated list of
+//! `tag=value` filters -- e.g.
+//! `BENCHMARK_SELECT="backend=Doublets_Split_NonVolatile,operation=update,size=100000"`
+//! -- and answers whether a given backend name, operation name, or sweep
+//! size is part of the requested cartesian product. Repeat a tag to select
+//! several values for it (`backend=Sqlite_Volatile,backend=Sqlite_NonVolatile`).
+//! An unset tag matches everything, so the default (no `BENCHMARK_SELECT` at
+//! all) runs the full suite exactly as before.
+//!
+//! Call sites check [`SELECTOR`] themselves rather than Criterion's own
+//! `--bench <filter>` flag, since that only matches the final benchmark id
+//! string (`"Doublets_Split_NonVolatile/100000"`) after every backend has
+//! already been constructed and registered -- `Selector` is checked before
+//! construction, so a filtered-out backend is never even set up.
+//!
+//! Pair this with the `BENCHMARK_LINK_COUNT`/`BENCHMARK_BACKGROUND_LINKS`
+//! environment variables (see [`crate::LINK_COUNT`]/[`crate::BACKGROUND_LINKS`])
+//! to also pin the dataset size for a single run, instead of editing the
+//! compiled-in defaults.
+
+use std::{collections::HashSet, env};
+
+use once_cell::sync::Lazy;
+
+/// Global selector parsed from `BENCHMARK_SELECT` once, at first use.
+pub static SELECTOR: Lazy<Selector> = Lazy::new(Selector::from_env);
+
+/// A parsed `BENCHMARK_SELECT` filter. `None` for a given dimension means
+/// "everything matches"; `Some(set)` means only the listed values do.
+#[derive(Debug, Default)]
+pub struct Selector {
+    backends: Option<HashSet<String>>,
+    operations: Option<HashSet<String>>,
+    sizes: Option<HashSet<usize>>,
+}
+
+impl Selector {
+    /// Parse `BENCHMARK_SELECT`. Falls back to "everything matches" if the
+    /// variable is unset, empty, or carries no recognized tags.
+    pub fn from_env() -> Self {
+        let Ok(raw) = env::var("BENCHMARK_SELECT") else {
+            return Self::default();
+        };
+
+        let mut selector = Self::default();
+        for tag in raw.split(',').map(str::trim).filter(|tag| !tag.is_empty()) {
+            let Some((key, value)) = tag.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "backend" => {
+                    selector
+                        .backends
+                        .get_or_insert_with(HashSet::new)
+                        .insert(value.to_string());
+                }
+                "operation" => {
+                    selector
+                        .operations
+                        .get_or_insert_with(HashSet::new)
+                        .insert(value.to_string());
+                }
+                "size" => {
+                    if let Ok(size) = value.parse() {
+                        selector.sizes.get_or_insert_with(HashSet::new).insert(size);
+                    }
+                }
+                _ => {}
+            }
+        }
+        selector
+    }
+
+    /// Whether `backend` (e.g. `"Doublets_Split_NonVolatile"`) is part of
+    /// the requested matrix.
+    pub fn backend(&self, backend: &str) -> bool {
+        self.backends
+            .as_ref()
+            .map_or(true, |set| set.contains(backend))
+    }
+
+    /// Whether `operation` (e.g. `"update"`, `"each_identity"`) is part of
+    /// the requested matrix.
+    pub fn operation(&self, operation: &str) -> bool {
+        self.operations
+            .as_ref()
+            .map_or(true, |set| set.contains(operation))
+    }
+
+    /// Whether a swept dataset `size` (e.g. a `BENCHMARK_SCALING_SIZES` or
+    /// `BENCHMARK_BATCH_SIZES` entry) is part of the requested matrix.
+    pub fn size(&self, size: usize) -> bool {
+        self.sizes.as_ref().map_or(true, |set| set.contains(&size))
+    }
+}
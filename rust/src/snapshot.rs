@@ -0,0 +1,209 @@
+//! # Delta + Bit-Packed Snapshot Encoding
+//!
+//! A backend-agnostic column codec for the `snapshot_export`/
+//! `snapshot_import` benchmarks (see [`crate`]'s per-backend modules in
+//! `benches/benchmarks/snapshot`). The id/source/target columns of a
+//! sorted link set are dense and produce mostly-tiny deltas -- ids are
+//! sequential, and a backend's source/target index tends to return sorted
+//! runs -- so each column is delta-encoded (subtract the previous value),
+//! zigzag-mapped to an unsigned integer so small negative deltas stay
+//! small, then split into blocks of [`BLOCK_SIZE`] values. Each block is
+//! prefixed with one width byte (the minimum bit width needed to hold
+//! every zigzag value in that block) and bit-packed to exactly that width,
+//! so a block of identical or near-identical values costs close to zero
+//! bits per value instead of a fixed 8 bytes.
+//!
+//! Decoding reverses the process per block: unpack `width`-bit values,
+//! zigzag-decode, then prefix-sum back to the original column.
+
+const BLOCK_SIZE: usize = 128;
+
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn bits_needed(value: u64) -> u8 {
+    if value == 0 {
+        0
+    } else {
+        64 - value.leading_zeros() as u8
+    }
+}
+
+/// Accumulates values of a given bit width into a packed byte stream.
+struct BitWriter {
+    bytes: Vec<u8>,
+    cur: u128,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            cur: 0,
+            bits: 0,
+        }
+    }
+
+    fn write(&mut self, value: u64, width: u8) {
+        if width == 0 {
+            return;
+        }
+        let mask: u128 = if width == 64 {
+            u64::MAX as u128
+        } else {
+            (1u128 << width) - 1
+        };
+        self.cur |= ((value as u128) & mask) << self.bits;
+        self.bits += width as u32;
+        while self.bits >= 8 {
+            self.bytes.push((self.cur & 0xFF) as u8);
+            self.cur >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push((self.cur & 0xFF) as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Reads back values of a given bit width from a packed byte stream.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    cur: u128,
+    bits: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            cur: 0,
+            bits: 0,
+        }
+    }
+
+    fn read(&mut self, width: u8) -> u64 {
+        if width == 0 {
+            return 0;
+        }
+        while self.bits < width as u32 {
+            let byte = self.bytes.get(self.pos).copied().unwrap_or(0);
+            self.pos += 1;
+            self.cur |= (byte as u128) << self.bits;
+            self.bits += 8;
+        }
+        let mask: u128 = if width == 64 {
+            u64::MAX as u128
+        } else {
+            (1u128 << width) - 1
+        };
+        let value = (self.cur & mask) as u64;
+        self.cur >>= width;
+        self.bits -= width as u32;
+        value
+    }
+}
+
+/// Delta + bit-packs `values` into `[width_byte, packed_payload]` blocks of
+/// up to [`BLOCK_SIZE`] values each.
+fn encode_column(values: &[i64]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut prev = 0i64;
+
+    for chunk in values.chunks(BLOCK_SIZE) {
+        let zigzags: Vec<u64> = chunk
+            .iter()
+            .map(|&value| {
+                let delta = value.wrapping_sub(prev);
+                prev = value;
+                zigzag_encode(delta)
+            })
+            .collect();
+
+        let width = zigzags.iter().map(|&z| bits_needed(z)).max().unwrap_or(0);
+        out.push(width);
+
+        let mut writer = BitWriter::new();
+        for z in zigzags {
+            writer.write(z, width);
+        }
+        out.extend(writer.finish());
+    }
+
+    out
+}
+
+/// Reverses [`encode_column`], reading exactly `count` values back.
+fn decode_column(bytes: &[u8], count: usize) -> Vec<i64> {
+    let mut values = Vec::with_capacity(count);
+    let mut prev = 0i64;
+    let mut pos = 0;
+    let mut remaining = count;
+
+    while remaining > 0 {
+        let width = bytes[pos];
+        pos += 1;
+
+        let block_len = remaining.min(BLOCK_SIZE);
+        let packed_bytes = (block_len * width as usize + 7) / 8;
+        let mut reader = BitReader::new(&bytes[pos..pos + packed_bytes]);
+
+        for _ in 0..block_len {
+            let delta = zigzag_decode(reader.read(width));
+            prev = prev.wrapping_add(delta);
+            values.push(prev);
+        }
+
+        pos += packed_bytes;
+        remaining -= block_len;
+    }
+
+    values
+}
+
+/// A delta + bit-packed snapshot of a link set's id/source/target columns.
+pub struct Snapshot {
+    link_count: usize,
+    ids: Vec<u8>,
+    sources: Vec<u8>,
+    targets: Vec<u8>,
+}
+
+impl Snapshot {
+    /// Encodes parallel id/source/target columns (all the same length)
+    /// into a [`Snapshot`].
+    pub fn encode(ids: &[i64], sources: &[i64], targets: &[i64]) -> Self {
+        Self {
+            link_count: ids.len(),
+            ids: encode_column(ids),
+            sources: encode_column(sources),
+            targets: encode_column(targets),
+        }
+    }
+
+    /// Decodes back into `(ids, sources, targets)` columns.
+    pub fn decode(&self) -> (Vec<i64>, Vec<i64>, Vec<i64>) {
+        (
+            decode_column(&self.ids, self.link_count),
+            decode_column(&self.sources, self.link_count),
+            decode_column(&self.targets, self.link_count),
+        )
+    }
+
+    /// Total encoded size in bytes across all three columns.
+    pub fn byte_len(&self) -> usize {
+        self.ids.len() + self.sources.len() + self.targets.len()
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use doublets::{
+    data::{Error, Flow, LinkType, LinksConstants, ReadHandler, WriteHandler},
+    Doublets, Link, Links,
+};
+use rusqlite::{params, params_from_iter, Connection};
+
+use crate::{Exclusive, Result, Sql};
+
+/// SQLite-backed links store.
+///
+/// Stores every link as a row in a single table:
+/// ```sql
+/// CREATE TABLE links (
+///     id INTEGER PRIMARY KEY,
+///     source INTEGER NOT NULL,
+///     target INTEGER NOT NULL
+/// );
+/// CREATE INDEX links_source ON links (source);
+/// CREATE INDEX links_target ON links (target);
+/// ```
+///
+/// `id` is SQLite's `rowid` alias, so `create_links` leaves it to the
+/// engine (`INSERT INTO links (source, target) VALUES (0, 0)`) and reads it
+/// back via `last_insert_rowid()`, mirroring the atomic-counter allocation
+/// `Client::fetch_next_id` does for Neo4j.
+pub struct Sqlite<T: LinkType> {
+    connection: Connection,
+    constants: LinksConstants<T>,
+    statements_executed: AtomicU64,
+    _marker: PhantomData<T>,
+}
+
+impl<T: LinkType> Sqlite<T> {
+    /// Open a database at `path`. Pass `":memory:"` for a volatile,
+    /// in-memory database (SQLite's own convention) or a filesystem path
+    /// for a persistent one.
+    pub fn open(path: &str) -> Result<Self> {
+        let connection = Connection::open(path).map_err(|e| e.to_string())?;
+        let mut store = Self {
+            connection,
+            constants: LinksConstants::new(),
+            statements_executed: AtomicU64::new(0),
+            _marker: PhantomData,
+        };
+        store.create_table()?;
+        Ok(store)
+    }
+
+    /// Number of SQL statements executed against [`Self::connection`] by the
+    /// [`Links`] methods below, so far. Counts every `count_links`/
+    /// `create_links`/`each_links`/`update_links`/`delete_links`/`get_link`
+    /// call as exactly one statement, matching how each of those methods is
+    /// implemented.
+    pub fn statements_executed(&self) -> u64 {
+        self.statements_executed.load(Ordering::Relaxed)
+    }
+}
+
+impl<T: LinkType> Sql for Sqlite<T> {
+    fn create_table(&mut self) -> Result<()> {
+        self.connection
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS links (
+                    id INTEGER PRIMARY KEY,
+                    source INTEGER NOT NULL,
+                    target INTEGER NOT NULL
+                );
+                CREATE INDEX IF NOT EXISTS links_source ON links (source);
+                CREATE INDEX IF NOT EXISTS links_target ON links (target);",
+            )
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    fn drop_table(&mut self) -> Result<()> {
+        self.connection
+            .execute("DELETE FROM links", [])
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+/// Build a `WHERE` clause (and its bound parameters) for a `[id, source,
+/// target]`-shaped query, mirroring the `any` -> omitted-predicate logic
+/// `Client::count_links`/`each_links` use for Neo4j.
+fn predicate<T: LinkType>(query: &[T], any: T) -> (String, Vec<i64>) {
+    let mut conditions = Vec::new();
+    let mut params = Vec::new();
+
+    if query.len() == 1 {
+        if query[0] != any {
+            conditions.push("id = ?".to_string());
+            params.push(query[0].as_i64());
+        }
+    } else if query.len() == 3 {
+        if query[0] != any {
+            conditions.push("id = ?".to_string());
+            params.push(query[0].as_i64());
+        }
+        if query[1] != any {
+            conditions.push("source = ?".to_string());
+            params.push(query[1].as_i64());
+        }
+        if query[2] != any {
+            conditions.push("target = ?".to_string());
+            params.push(query[2].as_i64());
+        }
+    } else if !query.is_empty() {
+        panic!("Constraints violation: size of query neither 1 nor 3")
+    }
+
+    if conditions.is_empty() {
+        (String::new(), params)
+    } else {
+        (format!(" WHERE {}", conditions.join(" AND ")), params)
+    }
+}
+
+impl<T: LinkType> Links<T> for Exclusive<Sqlite<T>> {
+    fn constants(&self) -> &LinksConstants<T> {
+        &self.constants
+    }
+
+    fn count_links(&self, query: &[T]) -> T {
+        let any = self.constants.any;
+        let (where_clause, params) = predicate(query, any);
+        let sql = format!("SELECT COUNT(*) FROM links{where_clause}");
+
+        self.get()
+            .statements_executed
+            .fetch_add(1, Ordering::Relaxed);
+
+        self.get()
+            .connection
+            .query_row(&sql, params_from_iter(params), |row| row.get::<_, i64>(0))
+            .ok()
+            .and_then(|count| count.try_into().ok())
+            .unwrap_or(T::ZERO)
+    }
+
+    fn create_links(
+        &mut self,
+        _query: &[T],
+        handler: WriteHandler<T>,
+    ) -> std::result::Result<Flow, Error<T>> {
+        self.statements_executed.fetch_add(1, Ordering::Relaxed);
+
+        let _ = self
+            .connection
+            .execute("INSERT INTO links (source, target) VALUES (0, 0)", []);
+        let id = self.connection.last_insert_rowid();
+
+        Ok(handler(
+            Link::nothing(),
+            Link::new(id.try_into().unwrap_or(T::ZERO), T::ZERO, T::ZERO),
+        ))
+    }
+
+    fn each_links(&self, query: &[T], handler: ReadHandler<T>) -> Flow {
+        let any = self.constants.any;
+        let (where_clause, params) = predicate(query, any);
+        let sql = format!("SELECT id, source, target FROM links{where_clause}");
+
+        self.get()
+            .statements_executed
+            .fetch_add(1, Ordering::Relaxed);
+
+        let connection = &self.get().connection;
+        let mut statement = match connection.prepare(&sql) {
+            Ok(statement) => statement,
+            Err(_) => return Flow::Continue,
+        };
+        let mut rows = match statement.query(params_from_iter(params)) {
+            Ok(rows) => rows,
+            Err(_) => return Flow::Continue,
+        };
+
+        while let Ok(Some(row)) = rows.next() {
+            let id: i64 = row.get(0).unwrap_or(0);
+            let source: i64 = row.get(1).unwrap_or(0);
+            let target: i64 = row.get(2).unwrap_or(0);
+
+            if let Flow::Break = handler(Link::new(
+                id.try_into().unwrap_or(T::ZERO),
+                source.try_into().unwrap_or(T::ZERO),
+                target.try_into().unwrap_or(T::ZERO),
+            )) {
+                return Flow::Break;
+            }
+        }
+
+        Flow::Continue
+    }
+
+    fn update_links(
+        &mut self,
+        query: &[T],
+        change: &[T],
+        handler: WriteHandler<T>,
+    ) -> std::result::Result<Flow, Error<T>> {
+        let id = query[0];
+        let source = change[1];
+        let target = change[2];
+
+        self.statements_executed.fetch_add(1, Ordering::Relaxed);
+
+        // Capture the pre-update row via a CTE, then update and hand both
+        // states back through a single RETURNING statement.
+        let old = self.connection.query_row(
+            "WITH old AS (SELECT source, target FROM links WHERE id = ?1)
+             UPDATE links SET source = ?2, target = ?3 WHERE id = ?1
+             RETURNING (SELECT source FROM old), (SELECT target FROM old)",
+            params![id.as_i64(), source.as_i64(), target.as_i64()],
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+        );
+
+        let (old_source, old_target) = match old {
+            Ok((s, t)) => (
+                s.try_into().unwrap_or(T::ZERO),
+                t.try_into().unwrap_or(T::ZERO),
+            ),
+            Err(_) => (T::ZERO, T::ZERO),
+        };
+
+        Ok(handler(
+            Link::new(id, old_source, old_target),
+            Link::new(id, source, target),
+        ))
+    }
+
+    fn delete_links(
+        &mut self,
+        query: &[T],
+        handler: WriteHandler<T>,
+    ) -> std::result::Result<Flow, Error<T>> {
+        let id = query[0];
+
+        self.statements_executed.fetch_add(1, Ordering::Relaxed);
+
+        let old = self.connection.query_row(
+            "DELETE FROM links WHERE id = ?1 RETURNING source, target",
+            params![id.as_i64()],
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+        );
+
+        match old {
+            Ok((source, target)) => Ok(handler(
+                Link::new(
+                    id,
+                    source.try_into().unwrap_or(T::ZERO),
+                    target.try_into().unwrap_or(T::ZERO),
+                ),
+                Link::nothing(),
+            )),
+            Err(_) => Err(Error::<T>::NotExists(id)),
+        }
+    }
+}
+
+impl<T: LinkType> Doublets<T> for Exclusive<Sqlite<T>> {
+    fn get_link(&self, index: T) -> Option<Link<T>> {
+        self.get()
+            .statements_executed
+            .fetch_add(1, Ordering::Relaxed);
+
+        self.get()
+            .connection
+            .query_row(
+                "SELECT source, target FROM links WHERE id = ?1",
+                params![index.as_i64()],
+                |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+            )
+            .ok()
+            .map(|(source, target): (i64, i64)| {
+                Link::new(
+                    index,
+                    source.try_into().unwrap_or(T::ZERO),
+                    target.try_into().unwrap_or(T::ZERO),
+                )
+            })
+    }
+}
@@ -0,0 +1,138 @@
+//! # Deterministic Graph Topology Generator
+//!
+//! The traversal benchmarks (see `benches/benchmarks/traversal`) need both
+//! backends to walk the *identical* graph so their wall times are
+//! comparable. This module generates that graph once, as plain data, so
+//! `traversal::doublets` and `traversal::neo4j` each build their own store
+//! from the same [`Edge`] list instead of maintaining two topology
+//! generators that could drift apart.
+//!
+//! The graph is a layered DAG: a single root (node `1`) fans out over
+//! [`traversal_depth`] layers of [`traversal_width`] nodes each, with
+//! [`traversal_fanout`] edges per node into the next layer, chosen by a
+//! small seeded PRNG (see [`Xorshift64`]) so the same
+//! [`BENCHMARK_TRAVERSAL_SEED`](traversal_seed) always produces the same
+//! edges -- no `rand` dependency needed for that.
+
+use std::env;
+
+/// A minimal xorshift64 PRNG. Not cryptographically strong, but this only
+/// needs to deterministically pick fan-out targets from a seed, not resist
+/// an adversary.
+pub struct Xorshift64(u64);
+
+impl Xorshift64 {
+    /// Seeds the generator, mapping a seed of `0` to `1` since xorshift
+    /// never advances away from an all-zero state.
+    pub fn new(seed: u64) -> Self {
+        Self(if seed == 0 { 1 } else { seed })
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    /// A value in `0..bound`.
+    pub fn gen_range(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// One `source -> target` edge of the synthetic traversal graph. `source`
+/// and `target` are node ids, not link ids -- each edge is materialized as
+/// its own link (with `source`/`target` set to these values) by
+/// `traversal::doublets`, or as a `POINTS_TO` relationship between two
+/// `:Link` nodes carrying these ids by `traversal::neo4j`.
+#[derive(Debug, Clone, Copy)]
+pub struct Edge {
+    pub source: usize,
+    pub target: usize,
+}
+
+/// A generated traversal graph: `edges` fan out from `start` over
+/// `max_depth` layers, touching `node_count` distinct node ids in total.
+pub struct Topology {
+    pub start: usize,
+    pub node_count: usize,
+    pub max_depth: usize,
+    pub edges: Vec<Edge>,
+}
+
+/// Number of layers fanning out from the root. Configurable via
+/// `BENCHMARK_TRAVERSAL_DEPTH`; defaults to 4.
+pub fn traversal_depth() -> usize {
+    env::var("BENCHMARK_TRAVERSAL_DEPTH")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(4)
+}
+
+/// Number of nodes per layer. Configurable via `BENCHMARK_TRAVERSAL_WIDTH`;
+/// defaults to 4.
+pub fn traversal_width() -> usize {
+    env::var("BENCHMARK_TRAVERSAL_WIDTH")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(4)
+}
+
+/// Number of edges fanning out from each node into the next layer.
+/// Configurable via `BENCHMARK_TRAVERSAL_FANOUT`; defaults to 2.
+pub fn traversal_fanout() -> usize {
+    env::var("BENCHMARK_TRAVERSAL_FANOUT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(2)
+}
+
+/// PRNG seed for picking fan-out targets. Configurable via
+/// `BENCHMARK_TRAVERSAL_SEED`; defaults to a fixed constant so the topology
+/// is reproducible across runs without having to set anything.
+pub fn traversal_seed() -> u64 {
+    env::var("BENCHMARK_TRAVERSAL_SEED")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0x5EED)
+}
+
+/// Generates the layered DAG described in the module docs from the
+/// `BENCHMARK_TRAVERSAL_*` knobs above. Deterministic: the same knobs
+/// always produce the same [`Topology`], which is what lets
+/// `traversal::doublets` and `traversal::neo4j` compare wall times on equal
+/// footing.
+pub fn generate() -> Topology {
+    let depth = traversal_depth();
+    let width = traversal_width();
+    let fanout = traversal_fanout();
+    let mut rng = Xorshift64::new(traversal_seed());
+
+    let mut edges = Vec::new();
+    let mut layer = vec![1usize];
+    let mut next_id = 2usize;
+
+    for _ in 0..depth {
+        let next_layer: Vec<usize> = (0..width).map(|offset| next_id + offset).collect();
+        next_id += width;
+
+        for &source in &layer {
+            for _ in 0..fanout {
+                let target = next_layer[rng.gen_range(next_layer.len())];
+                edges.push(Edge { source, target });
+            }
+        }
+
+        layer = next_layer;
+    }
+
+    Topology {
+        start: 1,
+        node_count: next_id - 1,
+        max_depth: depth,
+        edges,
+    }
+}
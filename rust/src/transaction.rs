@@ -1,10 +1,13 @@
-// Transaction wraps Client and delegates all operations to it.
-// In the HTTP-based approach using /db/neo4j/tx/commit endpoint,
-// all requests are auto-committed transactions.
+// Transaction owns a real, open Neo4j server-side transaction.
 //
-// This wrapper exists for API compatibility to benchmark "transactional"
-// Neo4j operations, which in this implementation are semantically
-// equivalent to non-transactional operations.
+// Unlike the auto-commit `/db/neo4j/tx/commit` endpoint `Client` uses
+// directly, this wraps the transactional Cypher HTTP protocol: `new` opens
+// a transaction (`POST /db/neo4j/tx`) and remembers its id; every
+// create/update/delete/each call appends a statement to that same open
+// transaction (`POST /db/neo4j/tx/{id}`); and `drop_table` commits it
+// (`POST /db/neo4j/tx/{id}/commit`), immediately reopening a fresh
+// transaction so the instance keeps working across benchmark iterations.
+// `Drop` commits whatever transaction is still open as a last resort.
 
 use doublets::{
     data::{Error, Flow, LinkType, LinksConstants, ReadHandler, WriteHandler},
@@ -16,11 +19,48 @@ use crate::{Client, Exclusive, Result, Sql};
 
 pub struct Transaction<'a, T: LinkType> {
     client: &'a Client<T>,
+    tx_id: String,
 }
 
 impl<'a, T: LinkType> Transaction<'a, T> {
     pub fn new(client: &'a Client<T>) -> Result<Self> {
-        Ok(Self { client })
+        let tx_id = client.begin_transaction()?;
+        Ok(Self { client, tx_id })
+    }
+
+    /// The client this transaction was opened on, e.g. to read its
+    /// HTTP round-trip/byte counters, or to open another transaction on
+    /// the same connection (see `Benched::fork_concurrent`).
+    pub fn client(&self) -> &'a Client<T> {
+        self.client
+    }
+
+    /// Commit the currently open transaction.
+    pub fn commit(&mut self) -> Result<()> {
+        self.client.commit_transaction(&self.tx_id)
+    }
+
+    /// Abort the currently open transaction, discarding its writes.
+    pub fn rollback(&mut self) -> Result<()> {
+        self.client.rollback_transaction(&self.tx_id)
+    }
+
+    /// Commit the currently open transaction and open a fresh one in its
+    /// place, so the same `Transaction` instance can keep being used.
+    fn recycle(&mut self) -> Result<()> {
+        self.commit()?;
+        self.tx_id = self.client.begin_transaction()?;
+        Ok(())
+    }
+
+    fn execute(&self, query: &str, params: Option<serde_json::Value>) -> Result<crate::client::CypherResponse> {
+        self.client.execute_in_transaction(&self.tx_id, query, params)
+    }
+}
+
+impl<T: LinkType> Drop for Transaction<'_, T> {
+    fn drop(&mut self) {
+        let _ = self.commit();
     }
 }
 
@@ -31,17 +71,14 @@ impl<T: LinkType> Sql for Transaction<'_, T> {
     }
 
     fn drop_table(&mut self) -> Result<()> {
-        // Delete all nodes - delegated to client
-        let _ = self
-            .client
-            .execute_cypher("MATCH (l:Link) DETACH DELETE l", None);
+        let _ = self.execute("MATCH (l:Link) DETACH DELETE l", None);
         // Reset the ID counter to ensure isolation between benchmark iterations
         self.client.reset_next_id();
-        Ok(())
+        self.recycle()
     }
 }
 
-// Transaction delegates all Links operations to the underlying Client
+// Transaction appends every operation to the single open transaction it owns
 impl<'a, T: LinkType> Links<T> for Exclusive<Transaction<'a, T>> {
     fn constants(&self) -> &LinksConstants<T> {
         self.client.constants()
@@ -86,7 +123,7 @@ impl<'a, T: LinkType> Links<T> for Exclusive<Transaction<'a, T>> {
             panic!("Constraints violation: size of query neither 1 nor 3")
         };
 
-        match self.client.execute_cypher(&cypher, None) {
+        match self.execute(&cypher, None) {
             Ok(response) => {
                 if let Some(result) = response.results.first() {
                     if let Some(row) = result.data.first() {
@@ -109,7 +146,7 @@ impl<'a, T: LinkType> Links<T> for Exclusive<Transaction<'a, T>> {
     ) -> std::result::Result<Flow, Error<T>> {
         let next_id = self.client.fetch_next_id();
 
-        let _ = self.client.execute_cypher(
+        let _ = self.execute(
             "CREATE (l:Link {id: $id, source: 0, target: 0})",
             Some(json!({ "id": next_id })),
         );
@@ -161,7 +198,7 @@ impl<'a, T: LinkType> Links<T> for Exclusive<Transaction<'a, T>> {
             panic!("Constraints violation: size of query neither 1 nor 3")
         };
 
-        match self.client.execute_cypher(&cypher, None) {
+        match self.execute(&cypher, None) {
             Ok(response) => {
                 if let Some(result) = response.results.first() {
                     for row in &result.data {
@@ -197,7 +234,7 @@ impl<'a, T: LinkType> Links<T> for Exclusive<Transaction<'a, T>> {
         let target = change[2];
 
         // Get old values
-        let old_result = self.client.execute_cypher(
+        let old_result = self.execute(
             "MATCH (l:Link {id: $id}) RETURN l.source as source, l.target as target",
             Some(json!({"id": id.as_i64()})),
         );
@@ -227,7 +264,7 @@ impl<'a, T: LinkType> Links<T> for Exclusive<Transaction<'a, T>> {
         };
 
         // Update
-        let _ = self.client.execute_cypher(
+        let _ = self.execute(
             "MATCH (l:Link {id: $id}) SET l.source = $source, l.target = $target",
             Some(json!({
                 "id": id.as_i64(),
@@ -250,7 +287,7 @@ impl<'a, T: LinkType> Links<T> for Exclusive<Transaction<'a, T>> {
         let id = query[0];
 
         // Get old values before deleting
-        let old_result = self.client.execute_cypher(
+        let old_result = self.execute(
             "MATCH (l:Link {id: $id}) RETURN l.source as source, l.target as target",
             Some(json!({"id": id.as_i64()})),
         );
@@ -280,7 +317,7 @@ impl<'a, T: LinkType> Links<T> for Exclusive<Transaction<'a, T>> {
         };
 
         // Delete
-        let _ = self.client.execute_cypher(
+        let _ = self.execute(
             "MATCH (l:Link {id: $id}) DELETE l",
             Some(json!({"id": id.as_i64()})),
         );
@@ -294,7 +331,7 @@ impl<'a, T: LinkType> Links<T> for Exclusive<Transaction<'a, T>> {
 
 impl<'a, T: LinkType> Doublets<T> for Exclusive<Transaction<'a, T>> {
     fn get_link(&self, index: T) -> Option<Link<T>> {
-        match self.client.execute_cypher(
+        match self.execute(
             "MATCH (l:Link {id: $id}) RETURN l.source as source, l.target as target",
             Some(json!({"id": index.as_i64()})),
         ) {
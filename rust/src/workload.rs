@@ -0,0 +1,165 @@
+//! # Seeded Graph Workload Generator
+//!
+//! `create_point()` self-loops give every link a trivial, uniform degree,
+//! which can't exercise a source/target index the way real graph data
+//! does -- a handful of hub nodes with very high degree, most nodes with
+//! very low degree. This module generates a reproducible `Vec<(source,
+//! target)>` edge stream from a fixed seed, using the same [`Xorshift64`]
+//! PRNG [`crate::topology`] uses, so `doublets::create`/`update` and
+//! `neo4j::create`/`update` (both generic over [`doublets::Doublets`], so
+//! the same replay code runs against either backend) populate storage with
+//! identical, non-trivial topology instead of sequential self-loops.
+//!
+//! Two degree distributions are available, selected by
+//! [`workload_mode`]/`BENCHMARK_WORKLOAD_MODE`:
+//!
+//! - `barabasi_albert` (default) -- preferential attachment: starting from
+//!   `m0` seed nodes, each new node picks `m` targets sampled from a
+//!   "repeated nodes" vector (each existing node id appears once per edge
+//!   it's incident to), so high-degree nodes are proportionally more
+//!   likely to be picked -- a Barabási–Albert scale-free graph.
+//! - `erdos_renyi` -- every edge's endpoints are drawn uniformly at
+//!   random, giving a flat degree distribution as the baseline to compare
+//!   the skewed one against.
+
+use std::{collections::HashSet, env};
+
+use crate::topology::Xorshift64;
+
+/// Degree-distribution family a workload is generated from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkloadMode {
+    BarabasiAlbert,
+    ErdosRenyi,
+}
+
+/// Selects the degree distribution via `BENCHMARK_WORKLOAD_MODE`
+/// (`barabasi_albert` or `erdos_renyi`); defaults to `barabasi_albert`.
+pub fn workload_mode() -> WorkloadMode {
+    match env::var("BENCHMARK_WORKLOAD_MODE").ok().as_deref() {
+        Some("erdos_renyi") => WorkloadMode::ErdosRenyi,
+        _ => WorkloadMode::BarabasiAlbert,
+    }
+}
+
+/// PRNG seed for the workload generator. Configurable via
+/// `BENCHMARK_WORKLOAD_SEED`; defaults to a fixed constant so the
+/// generated edges are reproducible across runs.
+pub fn workload_seed() -> u64 {
+    env::var("BENCHMARK_WORKLOAD_SEED")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0xC0FFEE)
+}
+
+/// Number of seed nodes the Barabási–Albert generator starts from.
+/// Configurable via `BENCHMARK_WORKLOAD_M0`; defaults to 5.
+pub fn workload_m0() -> usize {
+    env::var("BENCHMARK_WORKLOAD_M0")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(5)
+}
+
+/// Edges added per new node in Barabási–Albert mode (or per node sampled
+/// in Erdős–Rényi mode). Configurable via `BENCHMARK_WORKLOAD_M`; defaults
+/// to 2.
+pub fn workload_m() -> usize {
+    env::var("BENCHMARK_WORKLOAD_M")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(2)
+}
+
+/// An ordered edge stream generated for replay against a backend.
+pub struct Workload {
+    pub mode: WorkloadMode,
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// Generates exactly `edge_count` edges (source/target node ids, 1-based)
+/// from the `BENCHMARK_WORKLOAD_*` knobs above, in emission order.
+pub fn generate(edge_count: usize) -> Workload {
+    let seed = workload_seed();
+    match workload_mode() {
+        WorkloadMode::BarabasiAlbert => Workload {
+            mode: WorkloadMode::BarabasiAlbert,
+            edges: barabasi_albert(edge_count, workload_m0(), workload_m(), seed),
+        },
+        WorkloadMode::ErdosRenyi => Workload {
+            mode: WorkloadMode::ErdosRenyi,
+            edges: erdos_renyi(edge_count, workload_m().max(1), seed),
+        },
+    }
+}
+
+/// Barabási–Albert preferential attachment: `m0` seed nodes are connected
+/// in a ring, then each new node adds `m` edges to existing nodes sampled
+/// uniformly from `repeated_nodes` -- a vector holding one entry per edge
+/// endpoint seen so far, so a node's odds of being picked again are
+/// proportional to its current degree. Nodes keep being added until
+/// `edge_count` edges have been emitted; the result is truncated to
+/// exactly `edge_count` since the last node added may overshoot it.
+fn barabasi_albert(edge_count: usize, m0: usize, m: usize, seed: u64) -> Vec<(usize, usize)> {
+    let mut rng = Xorshift64::new(seed);
+    let m0 = m0.max(1);
+    let m = m.max(1);
+
+    let mut edges = Vec::with_capacity(edge_count);
+    let mut repeated_nodes = Vec::new();
+
+    for i in 1..=m0 {
+        let next = if i == m0 { 1 } else { i + 1 };
+        if m0 > 1 && edges.len() < edge_count {
+            edges.push((i, next));
+            repeated_nodes.push(i);
+            repeated_nodes.push(next);
+        }
+    }
+
+    let mut new_node = m0 + 1;
+    while edges.len() < edge_count {
+        let mut targets = HashSet::new();
+        let available = new_node - 1;
+        while targets.len() < m.min(available) {
+            let candidate = if repeated_nodes.is_empty() {
+                1 + rng.gen_range(available)
+            } else {
+                repeated_nodes[rng.gen_range(repeated_nodes.len())]
+            };
+            targets.insert(candidate);
+        }
+        for target in targets {
+            edges.push((new_node, target));
+            repeated_nodes.push(new_node);
+            repeated_nodes.push(target);
+        }
+        new_node += 1;
+    }
+
+    edges.truncate(edge_count);
+    edges
+}
+
+/// Erdős–Rényi uniform-random: every edge's source is the next node in
+/// `1..=edge_count.div_ceil(fanout)` and its target is sampled uniformly
+/// from the nodes seen so far, giving a flat degree distribution as the
+/// baseline [`barabasi_albert`]'s skew is compared against.
+fn erdos_renyi(edge_count: usize, fanout: usize, seed: u64) -> Vec<(usize, usize)> {
+    let mut rng = Xorshift64::new(seed);
+    let mut edges = Vec::with_capacity(edge_count);
+    let mut node = 1usize;
+
+    while edges.len() < edge_count {
+        for _ in 0..fanout {
+            if edges.len() >= edge_count {
+                break;
+            }
+            let target = 1 + rng.gen_range(node);
+            edges.push((node, target));
+        }
+        node += 1;
+    }
+
+    edges
+}